@@ -0,0 +1,218 @@
+use std::collections::BTreeSet;
+
+use crate::solver::{self, HIDDEN};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotLevel {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl BotLevel {
+    // Chance that a turn is thrown away on a letter the answer doesn't have
+    fn mistake_rate(&self) -> f64 {
+        match self {
+            BotLevel::Easy => 0.45,
+            BotLevel::Medium => 0.2,
+            BotLevel::Hard => 0.05,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BotLevel::Easy => "EASY",
+            BotLevel::Medium => "MEDIUM",
+            BotLevel::Hard => "HARD",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_uppercase().as_str() {
+            "EASY" => Some(BotLevel::Easy),
+            "MEDIUM" => Some(BotLevel::Medium),
+            "HARD" => Some(BotLevel::Hard),
+            _ => None,
+        }
+    }
+}
+
+// SplitMix64: tiny, seedable, good enough to decide when the bot slips
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> Option<T> {
+        if items.is_empty() {
+            None
+        } else {
+            Some(items[(self.next_u64() % items.len() as u64) as usize])
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BotRound {
+    pub guesses: Vec<char>, // in the order the bot played them
+    pub wrong_count: usize,
+    pub solved: bool,
+}
+
+fn board(answer: &[char], guessed: &BTreeSet<char>) -> String {
+    answer
+        .iter()
+        .map(|c| {
+            if c.is_ascii_lowercase() && !guessed.contains(c) {
+                HIDDEN
+            } else {
+                *c
+            }
+        })
+        .collect()
+}
+
+// Plays one card the way a player would see it: each turn the bot takes the
+// letter most of the still-possible answers contain (frequency analysis over
+// `corpus`), except that now and then it slips and plays a letter that misses.
+// Same inputs and seed give the same round.
+pub fn play_round(answer: &str, corpus: &[String], level: BotLevel, max_wrong: usize, seed: u64) -> BotRound {
+    let answer = solver::fold(answer);
+    let letters: BTreeSet<char> = answer.iter().copied().filter(char::is_ascii_lowercase).collect();
+
+    let mut rng = Rng(seed);
+    let mut guessed = BTreeSet::new();
+    let mut round = BotRound {
+        guesses: Vec::new(),
+        wrong_count: 0,
+        solved: letters.is_empty(),
+    };
+
+    while !round.solved && round.wrong_count < max_wrong {
+        let pattern = board(&answer, &guessed);
+        let played: Vec<char> = guessed.iter().copied().collect();
+
+        let mut ranked = solver::rank_letters(&pattern, &played, corpus);
+        ranked.sort_by(|a, b| {
+            b.hit_chance
+                .total_cmp(&a.hit_chance)
+                .then(b.info_gain.total_cmp(&a.info_gain))
+                .then(a.letter.cmp(&b.letter))
+        });
+
+        let misses: Vec<char> = ranked
+            .iter()
+            .map(|s| s.letter)
+            .filter(|c| !letters.contains(c))
+            .collect();
+
+        let slip = rng.next_f64() < level.mistake_rate();
+        let letter = match (slip, rng.pick(&misses)) {
+            (true, Some(miss)) => miss,
+            _ => match ranked.first() {
+                Some(best) => best.letter,
+                None => break,
+            },
+        };
+
+        guessed.insert(letter);
+        round.guesses.push(letter);
+
+        if letters.contains(&letter) {
+            round.solved = letters.iter().all(|c| guessed.contains(c));
+        } else {
+            round.wrong_count += 1;
+        }
+    }
+
+    round
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundOutcome {
+    PlayerWin,
+    BotWin,
+    Draw,
+}
+
+impl RoundOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundOutcome::PlayerWin => "PLAYER",
+            RoundOutcome::BotWin => "BOT",
+            RoundOutcome::Draw => "DRAW",
+        }
+    }
+}
+
+// Solving beats not solving; between two solvers fewer wrong guesses wins
+pub fn decide(player_solved: bool, player_wrong: usize, bot: &BotRound) -> RoundOutcome {
+    match (player_solved, bot.solved) {
+        (true, false) => RoundOutcome::PlayerWin,
+        (false, true) => RoundOutcome::BotWin,
+        (false, false) => RoundOutcome::Draw,
+        (true, true) => match player_wrong.cmp(&bot.wrong_count) {
+            std::cmp::Ordering::Less => RoundOutcome::PlayerWin,
+            std::cmp::Ordering::Greater => RoundOutcome::BotWin,
+            std::cmp::Ordering::Equal => RoundOutcome::Draw,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpus() -> Vec<String> {
+        ["Femur", "Tibia", "Fibula", "Humerus", "Radius", "Ulna", "Patella", "Sternum", "Os ilium"]
+            .iter()
+            .map(|t| t.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn same_seed_same_round() {
+        let a = play_round("Fibula", &corpus(), BotLevel::Medium, 6, 42);
+        let b = play_round("Fibula", &corpus(), BotLevel::Medium, 6, 42);
+        assert_eq!(a, b);
+        assert!(a.guesses.len() >= 5);
+    }
+
+    #[test]
+    fn hard_bot_beats_easy_bot_on_average() {
+        let wrong = |level| -> usize {
+            (0..200)
+                .map(|seed| play_round("Patella", &corpus(), level, 6, seed).wrong_count)
+                .sum()
+        };
+        assert!(wrong(BotLevel::Hard) < wrong(BotLevel::Easy));
+    }
+
+    #[test]
+    fn bot_stops_at_max_wrong_and_handles_spaces() {
+        let r = play_round("Os ilium", &[], BotLevel::Easy, 1, 7);
+        assert!(r.solved || r.wrong_count == 1);
+
+        let solved = play_round("Os ilium", &corpus(), BotLevel::Hard, 6, 1);
+        assert!(!solved.guesses.contains(&' '));
+    }
+
+    #[test]
+    fn fewer_wrong_guesses_wins() {
+        let bot = BotRound { guesses: vec![], wrong_count: 2, solved: true };
+        assert_eq!(decide(true, 1, &bot), RoundOutcome::PlayerWin);
+        assert_eq!(decide(true, 2, &bot), RoundOutcome::Draw);
+        assert_eq!(decide(true, 3, &bot), RoundOutcome::BotWin);
+        assert_eq!(decide(false, 0, &bot), RoundOutcome::BotWin);
+    }
+}
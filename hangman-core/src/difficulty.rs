@@ -0,0 +1,126 @@
+use crate::text::normalize_term;
+
+// How many plays it takes before history counts as much as the term itself
+const PRIOR_ATTEMPTS: f64 = 10.0;
+
+// Tier boundaries on the 0..=1 score
+const EASY_BELOW: f64 = 0.45;
+const HARD_FROM: f64 = 0.62;
+
+// Letters from most to least frequent in running text. Latin and English
+// terms share the order; it only has to rank letters, not be exact.
+pub(crate) const LETTERS_BY_FREQUENCY: &str = "etaoinshrdlcumwfgypbvkjxqz";
+
+// 0 for 'e' up to 1 for 'z'; letters outside a-z count as rare
+fn letter_rarity(c: char) -> f64 {
+    let last = (LETTERS_BY_FREQUENCY.len() - 1) as f64;
+    match LETTERS_BY_FREQUENCY.find(c) {
+        Some(rank) => rank as f64 / last,
+        None => 1.0,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DifficultyTier {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl DifficultyTier {
+    pub fn from_score(score: f64) -> Self {
+        if score < EASY_BELOW {
+            DifficultyTier::Easy
+        } else if score < HARD_FROM {
+            DifficultyTier::Medium
+        } else {
+            DifficultyTier::Hard
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DifficultyTier::Easy => "EASY",
+            DifficultyTier::Medium => "MEDIUM",
+            DifficultyTier::Hard => "HARD",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_uppercase().as_str() {
+            "EASY" => Some(DifficultyTier::Easy),
+            "MEDIUM" => Some(DifficultyTier::Medium),
+            "HARD" => Some(DifficultyTier::Hard),
+            _ => None,
+        }
+    }
+}
+
+// Difficulty of guessing a term in 0..=1, from the term alone.
+// Short single words made of rare letters are the hardest: there are few
+// letters to hit and the usual first guesses (e, a, t, ...) miss.
+pub fn term_difficulty(term: &str) -> f64 {
+    let term = normalize_term(term);
+    let letters: Vec<char> = term.chars().filter(|c| c.is_alphabetic()).collect();
+
+    if letters.is_empty() {
+        return 0.0;
+    }
+
+    let mut distinct = letters.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+
+    let rarity = distinct
+        .iter()
+        .map(|c| letter_rarity(*c))
+        .sum::<f64>()
+        / distinct.len() as f64;
+
+    let shortness = 1.0 - ((letters.len() as f64 - 3.0) / 12.0).clamp(0.0, 1.0);
+    let single_word = 1.0 / term.split(' ').count() as f64;
+
+    (0.5 * rarity + 0.3 * shortness + 0.2 * single_word).clamp(0.0, 1.0)
+}
+
+// Moves the term-based score toward the observed loss rate as plays pile up
+pub fn refine_with_history(base: f64, attempts: i64, wins: i64) -> f64 {
+    if attempts <= 0 {
+        return base;
+    }
+
+    let attempts = attempts as f64;
+    let loss_rate = 1.0 - (wins as f64 / attempts).clamp(0.0, 1.0);
+    let weight = attempts / (attempts + PRIOR_ATTEMPTS);
+
+    ((1.0 - weight) * base + weight * loss_rate).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_rare_words_score_higher() {
+        assert!(term_difficulty("Jaw") > term_difficulty("Heart"));
+        assert!(term_difficulty("Heart") > term_difficulty("Sternocleidomastoid muscle"));
+        assert_eq!(term_difficulty(" - "), 0.0);
+    }
+
+    #[test]
+    fn history_takes_over_with_more_plays() {
+        let base = 0.2;
+        assert_eq!(refine_with_history(base, 0, 0), base);
+        let few = refine_with_history(base, 2, 0);
+        let many = refine_with_history(base, 200, 0);
+        assert!(base < few && few < many && many > 0.9);
+    }
+
+    #[test]
+    fn tiers_round_trip() {
+        assert_eq!(DifficultyTier::from_score(0.1), DifficultyTier::Easy);
+        assert_eq!(DifficultyTier::from_score(0.5), DifficultyTier::Medium);
+        assert_eq!(DifficultyTier::from_score(0.9), DifficultyTier::Hard);
+        assert_eq!(DifficultyTier::parse("hard"), Some(DifficultyTier::Hard));
+    }
+}
@@ -1,7 +1,17 @@
+pub mod bot;
+pub mod difficulty;
+pub mod rating;
+pub mod solver;
+pub mod text;
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
 
+pub fn hello() -> String {
+    "Hello from hangman-core ✅".to_string()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -12,8 +22,3 @@ mod tests {
         assert_eq!(result, 4);
     }
 }
-
-pub fn hello() -> String {
-    "Hello from hangman-core ✅".to_string()
-}
-
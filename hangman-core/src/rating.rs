@@ -0,0 +1,88 @@
+// Elo ratings for players and cards. Every resolved attempt is a match
+// between the player and the card: a win for the player is a loss for the card.
+
+pub const DEFAULT_RATING: f64 = 1500.0;
+
+// New ratings move fast and settle as games pile up
+const K_NEW: f64 = 40.0;
+const K_SETTLED: f64 = 16.0;
+const GAMES_TO_SETTLE: i64 = 30;
+
+// Chance that `rating` beats `opponent`
+pub fn expected_score(rating: f64, opponent: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((opponent - rating) / 400.0))
+}
+
+// K factor after `games` rated games
+pub fn k_factor(games: i64) -> f64 {
+    let settled = (games.max(0) as f64 / GAMES_TO_SETTLE as f64).min(1.0);
+    K_NEW - (K_NEW - K_SETTLED) * settled
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rated {
+    pub rating: f64,
+    pub games: i64,
+}
+
+impl Rated {
+    pub fn new() -> Self {
+        Self {
+            rating: DEFAULT_RATING,
+            games: 0,
+        }
+    }
+}
+
+impl Default for Rated {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// New (player, card) ratings after one attempt
+pub fn play(player: Rated, card: Rated, player_won: bool) -> (Rated, Rated) {
+    let score = if player_won { 1.0 } else { 0.0 };
+    let expected = expected_score(player.rating, card.rating);
+
+    let player_next = Rated {
+        rating: player.rating + k_factor(player.games) * (score - expected),
+        games: player.games + 1,
+    };
+    let card_next = Rated {
+        rating: card.rating + k_factor(card.games) * (expected - score),
+        games: card.games + 1,
+    };
+
+    (player_next, card_next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_ratings_move_symmetrically() {
+        let (p, c) = play(Rated::new(), Rated::new(), true);
+        assert_eq!(p.rating, DEFAULT_RATING + 20.0);
+        assert_eq!(c.rating, DEFAULT_RATING - 20.0);
+        assert_eq!((p.games, c.games), (1, 1));
+    }
+
+    #[test]
+    fn beating_an_easy_card_is_worth_little() {
+        let easy = Rated { rating: 1100.0, games: 100 };
+        let hard = Rated { rating: 1900.0, games: 100 };
+        let (vs_easy, _) = play(Rated::new(), easy, true);
+        let (vs_hard, _) = play(Rated::new(), hard, true);
+        assert!(vs_easy.rating - DEFAULT_RATING < 5.0);
+        assert!(vs_hard.rating - DEFAULT_RATING > 35.0);
+    }
+
+    #[test]
+    fn k_settles_after_enough_games() {
+        assert_eq!(k_factor(0), K_NEW);
+        assert_eq!(k_factor(GAMES_TO_SETTLE), K_SETTLED);
+        assert_eq!(k_factor(500), K_SETTLED);
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::difficulty::LETTERS_BY_FREQUENCY;
+use crate::text::fold_chars;
+
+// Hidden letter in a masked pattern ("f_m_r")
+pub const HIDDEN: char = '_';
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LetterScore {
+    pub letter: char,
+    pub info_gain: f64,  // bits: how much guessing it narrows the candidates
+    pub hit_chance: f64, // share of candidates that contain it
+}
+
+// Same folding as the game screen: strip diacritics, lowercase, keep every
+// other character so positions line up with the pattern.
+pub fn fold(term: &str) -> Vec<char> {
+    fold_chars(term).collect()
+}
+
+fn is_guessable(c: char) -> bool {
+    c.is_ascii_lowercase()
+}
+
+// A term can be the answer if revealed letters sit where the pattern shows
+// them and every hidden slot holds a letter nobody has guessed yet.
+fn fits(term: &[char], pattern: &[char], guessed: &BTreeSet<char>) -> bool {
+    term.len() == pattern.len()
+        && term.iter().zip(pattern).all(|(t, p)| {
+            if *p == HIDDEN {
+                is_guessable(*t) && !guessed.contains(t)
+            } else {
+                t == p
+            }
+        })
+}
+
+// Corpus terms (folded, deduplicated, sorted) that still fit the board
+pub fn candidates(pattern: &str, guessed: &[char], corpus: &[String]) -> Vec<String> {
+    let pattern = fold(pattern);
+    let guessed: BTreeSet<char> = guessed.iter().flat_map(|c| c.to_lowercase()).collect();
+
+    corpus
+        .iter()
+        .map(|t| fold(t))
+        .filter(|t| fits(t, &pattern, &guessed))
+        .map(|t| t.into_iter().collect::<String>())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+// Unguessed letters ranked by expected information gain over the remaining
+// candidates, i.e. the entropy of where the letter would show up (a miss is
+// one outcome too). Ties go to the likelier hit, then alphabetical order, so
+// the same board always gives the same answer. With no candidates left the
+// letters come back in general frequency order with zero scores.
+pub fn rank_letters(pattern: &str, guessed: &[char], corpus: &[String]) -> Vec<LetterScore> {
+    let guessed_set: BTreeSet<char> = guessed.iter().flat_map(|c| c.to_lowercase()).collect();
+    let remaining = candidates(pattern, guessed, corpus);
+
+    let open_letters = LETTERS_BY_FREQUENCY.chars().filter(|c| !guessed_set.contains(c));
+
+    if remaining.is_empty() {
+        return open_letters
+            .map(|letter| LetterScore {
+                letter,
+                info_gain: 0.0,
+                hit_chance: 0.0,
+            })
+            .collect();
+    }
+
+    let total = remaining.len() as f64;
+
+    let mut scores: Vec<LetterScore> = open_letters
+        .map(|letter| {
+            // outcome = positions where the letter would be revealed
+            let mut outcomes: BTreeMap<Vec<usize>, usize> = BTreeMap::new();
+            for term in &remaining {
+                let positions = term
+                    .chars()
+                    .enumerate()
+                    .filter(|(_, c)| *c == letter)
+                    .map(|(i, _)| i)
+                    .collect();
+                *outcomes.entry(positions).or_default() += 1;
+            }
+
+            // a letter every candidate answers the same way tells nothing;
+            // 0.0 here, since the sum below would give -0.0
+            let info_gain = if outcomes.len() == 1 {
+                0.0
+            } else {
+                outcomes
+                    .values()
+                    .map(|n| {
+                        let p = *n as f64 / total;
+                        -p * p.log2()
+                    })
+                    .sum::<f64>()
+            };
+            let misses = outcomes.get(&Vec::new()).copied().unwrap_or(0);
+
+            LetterScore {
+                letter,
+                info_gain,
+                hit_chance: 1.0 - misses as f64 / total,
+            }
+        })
+        .collect();
+
+    scores.sort_by(|a, b| {
+        b.info_gain
+            .total_cmp(&a.info_gain)
+            .then(b.hit_chance.total_cmp(&a.hit_chance))
+            .then(a.letter.cmp(&b.letter))
+    });
+    scores
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn corpus(terms: &[&str]) -> Vec<String> {
+        terms.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn filters_by_pattern_and_misses() {
+        let c = corpus(&["Femur", "Fémur", "Tibia", "Fibula", "Humerus", "Lemur"]);
+        assert_eq!(candidates("_____", &[], &c), vec!["femur", "lemur", "tibia"]);
+        assert_eq!(candidates("_e_u_", &['e', 'u'], &c), vec!["femur", "lemur"]);
+        // 'l' was a miss, so lemur is out
+        assert_eq!(candidates("_e_u_", &['e', 'u', 'l'], &c), vec!["femur"]);
+        // a guessed letter can't hide behind '_'
+        assert!(candidates("_e_u_", &['e', 'u', 'm'], &c).is_empty());
+    }
+
+    #[test]
+    fn best_letter_splits_the_candidates() {
+        let c = corpus(&["cat", "bat", "hat", "car"]);
+        let ranked = rank_letters("___", &[], &c);
+        // 't' separates cat/bat/hat from car, 'c' separates cat/car from the rest
+        assert_eq!(ranked[0].letter, 'c');
+        assert_eq!(ranked[0].info_gain, 1.0);
+        assert!(ranked.iter().all(|s| s.letter != 'z' || s.info_gain == 0.0));
+        assert_eq!(ranked.iter().find(|s| s.letter == 'a').unwrap().hit_chance, 1.0);
+    }
+
+    #[test]
+    fn single_candidate_scores_plain_zero() {
+        let ranked = rank_letters("_____", &[], &corpus(&["Femur"]));
+        assert!(ranked.iter().all(|s| s.info_gain.to_bits() == 0.0f64.to_bits()));
+    }
+
+    #[test]
+    fn is_deterministic_and_falls_back_to_frequency() {
+        let c = corpus(&["os ilium", "os pubis", "os sacrum"]);
+        let a = rank_letters("os _____", &['o', 's'], &c);
+        let b = rank_letters("os _____", &['s', 'o'], &c);
+        assert_eq!(a, b);
+
+        let none = rank_letters("__", &['e'], &c);
+        assert_eq!(none[0].letter, 't');
+        assert_eq!(none.len(), 25);
+    }
+}
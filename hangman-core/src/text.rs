@@ -0,0 +1,147 @@
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+// Score above which two terms are reported as a likely duplicate
+pub const LIKELY_DUPLICATE: f64 = 0.9;
+
+// Words shorter than this are ignored when comparing word by word ("os", "of", ...)
+const MIN_WORD_LEN: usize = 4;
+
+// Terms at least this long that differ by one typo count as likely duplicates
+const MIN_TYPO_LEN: usize = 5;
+
+// Latin case and number endings, longest first ("femoris" -> "femor", "radii" -> "rad")
+const LATIN_ENDINGS: [&str; 13] = [
+    "ibus", "orum", "arum", "ium", "is", "us", "um", "ae", "es", "ii", "i", "a", "e",
+];
+
+// Lowercase and strip diacritics, keeping every other character: "Fémur" -> "femur"
+pub fn fold_chars(term: &str) -> impl Iterator<Item = char> + '_ {
+    term.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .flat_map(char::to_lowercase)
+}
+
+// Lowercase, strip diacritics and punctuation, collapse whitespace.
+// "  Os  Fémoris. " -> "os femoris"
+pub fn normalize_term(term: &str) -> String {
+    let folded: String = fold_chars(term)
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// Word without its Latin ending, keeping at least three letters
+fn stem(word: &str) -> &str {
+    LATIN_ENDINGS
+        .iter()
+        .find_map(|e| word.strip_suffix(e).filter(|s| s.chars().count() >= 3))
+        .unwrap_or(word)
+}
+
+// Similarity of two terms in 0..=1 (1 = same after normalization).
+// Best of: the whole term, the term without spaces and the longer words
+// compared pairwise in order (scored by the weakest pair, on the word and
+// on its Latin stem). One typo in a longer term scores at least LIKELY_DUPLICATE.
+pub fn term_similarity(a: &str, b: &str) -> f64 {
+    let a = normalize_term(a);
+    let b = normalize_term(b);
+
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+
+    let whole = strsim::jaro_winkler(&a, &b);
+    let (ca, cb) = (a.replace(' ', ""), b.replace(' ', ""));
+    let compact = strsim::jaro_winkler(&ca, &cb);
+
+    let words = |s: &str| {
+        s.split(' ')
+            .filter(|w| w.chars().count() >= MIN_WORD_LEN)
+            .map(str::to_string)
+            .collect::<Vec<_>>()
+    };
+    let (wa, wb) = (words(&a), words(&b));
+
+    // word by word, in order: every longer word has to match its counterpart,
+    // so "Frontal bone" / "Nasal bone" do not match on "bone" alone
+    let by_word = if !wa.is_empty() && wa.len() == wb.len() {
+        wa.iter()
+            .zip(&wb)
+            .map(|(x, y)| strsim::jaro_winkler(x, y).max(strsim::jaro_winkler(stem(x), stem(y))))
+            .fold(1.0, f64::min)
+    } else {
+        0.0
+    };
+
+    let typo = if ca.chars().count().min(cb.chars().count()) >= MIN_TYPO_LEN
+        && strsim::damerau_levenshtein(&ca, &cb) == 1
+    {
+        LIKELY_DUPLICATE
+    } else {
+        0.0
+    };
+
+    whole.max(compact).max(by_word).max(typo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_case_spacing_and_diacritics() {
+        assert_eq!(normalize_term("  Os  Fémoris. "), "os femoris");
+        assert_eq!(normalize_term("Femur "), normalize_term("femur"));
+        assert_eq!(normalize_term("Thigh-bone"), "thigh bone");
+    }
+
+    #[test]
+    fn similar_terms_score_high() {
+        assert_eq!(term_similarity("Femur", "femur "), 1.0);
+        assert!(term_similarity("Clavicle", "Clavicula") >= LIKELY_DUPLICATE);
+        assert!(term_similarity("Thigh bone", "thighbone") >= LIKELY_DUPLICATE);
+        assert!(term_similarity("Os femoris", "Femoris") >= LIKELY_DUPLICATE);
+    }
+
+    #[test]
+    fn latin_forms_and_typos_are_likely_duplicates() {
+        for (a, b) in [
+            ("Os femoris", "Femur"),
+            ("Radius", "Radii"),
+            ("Humerus", "Humeri"),
+            ("Femur", "Lemur"),
+            ("Heart", "Hearth"),
+        ] {
+            let score = term_similarity(a, b);
+            assert!(score >= LIKELY_DUPLICATE, "{a} / {b} scored {score}");
+        }
+    }
+
+    #[test]
+    fn different_terms_score_low() {
+        assert!(term_similarity("Tibia", "Fibula") < LIKELY_DUPLICATE);
+        assert!(term_similarity("Hepar", "Heart") < LIKELY_DUPLICATE);
+        assert!(term_similarity("Humerus", "Femur") < LIKELY_DUPLICATE);
+        assert!(term_similarity("Carpus", "Tarsus") < LIKELY_DUPLICATE);
+        assert!(term_similarity("Costa", "Coxa") < LIKELY_DUPLICATE);
+        assert_eq!(term_similarity("", "Femur"), 0.0);
+    }
+
+    #[test]
+    fn one_shared_word_is_not_a_duplicate() {
+        for (a, b) in [
+            ("Frontal bone", "Nasal bone"),
+            ("Carpal bones", "Tarsal bones"),
+            ("Superior vena cava", "Inferior vena cava"),
+            ("Spinal cord", "Spinal nerve"),
+        ] {
+            let score = term_similarity(a, b);
+            assert!(score < LIKELY_DUPLICATE, "{a} / {b} scored {score}");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use sqlx::SqlitePool;
+use tauri::State;
+
+use crate::domain::dto::{AuditLogEntry, AuditLogFilter, Page};
+use crate::errors::AppError;
+use crate::services::audit_service::AuditService;
+
+// JS: invoke("list_audit_log", { sessionToken, filter: { action: "CARD_APPROVE", page: 1, pageSize: 50 } })
+#[tauri::command]
+pub async fn list_audit_log(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    filter: Option<AuditLogFilter>,
+) -> Result<Page<AuditLogEntry>, AppError> {
+    AuditService::list_audit_log(&pool, session_token, filter.unwrap_or_default()).await
+}
@@ -1,9 +1,12 @@
 use sqlx::SqlitePool;
 use tauri::State;
 
+use crate::config::AppConfig;
 use crate::domain::dto::LoginResponse;
 use crate::services::auth_service::AuthService;
-use crate::domain::dto::MeResponse;
+use crate::domain::dto::{MeResponse, SessionItem};
+use crate::errors::AppError;
+use crate::services::session_service::SessionService;
 
 #[tauri::command]
 pub async fn register_user(
@@ -13,24 +16,26 @@ pub async fn register_user(
     username: String,
     email: String,
     password: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     AuthService::register_user(&pool, first_name, last_name, username, email, password).await
 }
 
 #[tauri::command]
 pub async fn login_user(
     pool: State<'_, SqlitePool>,
+    config: State<'_, AppConfig>,
     identifier: String,
     password: String,
-) -> Result<LoginResponse, String> {
-    AuthService::login_user(&pool, identifier, password).await
+    device_label: Option<String>,
+) -> Result<LoginResponse, AppError> {
+    AuthService::login_user(&pool, identifier, password, device_label, &config).await
 }
 
 #[tauri::command]
 pub async fn validate_session(
     pool: State<'_, SqlitePool>,
     session_token: String,
-) -> Result<bool, String> {
+) -> Result<bool, AppError> {
     AuthService::validate_session(&pool, session_token).await
 }
 
@@ -38,7 +43,7 @@ pub async fn validate_session(
 pub async fn logout(
     pool: State<'_, SqlitePool>,
     session_token: String,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     AuthService::logout(&pool, session_token).await
 }
 
@@ -46,6 +51,32 @@ pub async fn logout(
 pub async fn get_me(
     pool: State<'_, SqlitePool>,
     session_token: String,
-) -> Result<MeResponse, String> {
+) -> Result<MeResponse, AppError> {
     AuthService::get_me(&pool, session_token).await
 }
+
+#[tauri::command]
+pub async fn list_sessions(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<SessionItem>, AppError> {
+    SessionService::list_sessions(&pool, session_token).await
+}
+
+#[tauri::command]
+pub async fn revoke_session(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    session_id: i64,
+) -> Result<(), AppError> {
+    SessionService::revoke_session(&pool, session_token, session_id).await
+}
+
+// returns how many sessions were revoked
+#[tauri::command]
+pub async fn revoke_all_other_sessions(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<u64, AppError> {
+    SessionService::revoke_all_other_sessions(&pool, session_token).await
+}
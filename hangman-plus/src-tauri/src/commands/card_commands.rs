@@ -4,24 +4,45 @@ use tauri::State;
 use crate::domain::dto::{CardResponse, CreateCardInput};
 use crate::services::card_service::CardService;
 use crate::domain::dto::PendingCard;
-use crate::domain::dto::{CardAdminItem, UpdateCardInput};
+use crate::domain::dto::{
+    ArchivedCard, BatchReviewResult, CardAdminItem, CardListQuery, CardRevision, CardSearchHit,
+    MissingImageCard, MyCardRequest, Page, PurgeCardResult, ResubmitCardInput, UpdateCardInput,
+};
+use crate::domain::dto::ImportReport;
+use crate::errors::AppError;
+use crate::services::import_service::ImportService;
+use crate::services::difficulty_service::DifficultyService;
+use crate::services::search_service::SearchService;
+use crate::utils::assets::AssetRoots;
 
 
 
 #[tauri::command]
 pub async fn admin_add_card(
     pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
     input: CreateCardInput,
-) -> Result<CardResponse, String> {
-    CardService::add_card_admin(&pool, input).await
+) -> Result<CardResponse, AppError> {
+    CardService::add_card_admin(&pool, &assets, input).await
 }
 
 #[tauri::command]
 pub async fn user_request_card(
     pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
     input: CreateCardInput,
-) -> Result<CardResponse, String> {
-    CardService::request_card_user(&pool, input).await
+) -> Result<CardResponse, AppError> {
+    CardService::request_card_user(&pool, &assets, input).await
+}
+
+// JS: invoke("resubmit_card_request", { input: { sessionToken, cardId, category, english, latin, imagePath, region } })
+#[tauri::command]
+pub async fn resubmit_card_request(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    input: ResubmitCardInput,
+) -> Result<CardResponse, AppError> {
+    CardService::resubmit_card_request(&pool, &assets, input).await
 }
 
 #[tauri::command]
@@ -29,8 +50,9 @@ pub async fn approve_card(
     pool: State<'_, SqlitePool>,
     session_token: String,
     card_id: i64,
-) -> Result<(), String> {
-    CardService::approve_card(&pool, session_token, card_id).await
+    comment: Option<String>,
+) -> Result<(), AppError> {
+    CardService::approve_card(&pool, session_token, card_id, comment).await
 }
 
 #[tauri::command]
@@ -38,41 +60,92 @@ pub async fn reject_card(
     pool: State<'_, SqlitePool>,
     session_token: String,
     card_id: i64,
-) -> Result<(), String> {
-    CardService::reject_card(&pool, session_token, card_id).await
+    comment: Option<String>,
+) -> Result<(), AppError> {
+    CardService::reject_card(&pool, session_token, card_id, comment).await
 }
 
 
+// JS: invoke("approve_cards", { sessionToken, cardIds: [1, 2, 3], comment })
+#[tauri::command]
+pub async fn approve_cards(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    card_ids: Vec<i64>,
+    comment: Option<String>,
+) -> Result<Vec<BatchReviewResult>, AppError> {
+    CardService::approve_cards(&pool, session_token, card_ids, comment).await
+}
+
+#[tauri::command]
+pub async fn reject_cards(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    card_ids: Vec<i64>,
+    comment: Option<String>,
+) -> Result<Vec<BatchReviewResult>, AppError> {
+    CardService::reject_cards(&pool, session_token, card_ids, comment).await
+}
+
+// JS: invoke("list_pending_cards", { sessionToken, query: { search: "fem", sort: "OLDEST", page: 1 } })
 #[tauri::command]
 pub async fn list_pending_cards(
     pool: State<'_, SqlitePool>,
     sessionToken: String,
-) -> Result<Vec<PendingCard>, String> {
-    CardService::list_pending_cards(&pool, sessionToken).await
+    query: Option<CardListQuery>,
+) -> Result<Page<PendingCard>, AppError> {
+    CardService::list_pending_cards(&pool, sessionToken, query.unwrap_or_default()).await
+}
+
+#[tauri::command]
+pub async fn list_my_card_requests(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<MyCardRequest>, AppError> {
+    CardService::list_my_card_requests(&pool, session_token).await
+}
+
+#[tauri::command]
+pub async fn count_unread_card_decisions(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<i64, AppError> {
+    CardService::count_unread_card_decisions(&pool, session_token).await
+}
+
+#[tauri::command]
+pub async fn mark_card_decisions_seen(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<(), AppError> {
+    CardService::mark_card_decisions_seen(&pool, session_token).await
 }
 
 #[tauri::command]
 pub async fn count_pending_cards(
     pool: State<'_, SqlitePool>,
     sessionToken: String,
-) -> Result<i64, String> {
+) -> Result<i64, AppError> {
     CardService::count_pending_cards(&pool, sessionToken).await
 }
 
+// JS: invoke("list_all_cards_admin", { sessionToken, query: { status: "APPROVED", category: "BONES", page: 2, pageSize: 50 } })
 #[tauri::command]
 pub async fn list_all_cards_admin(
     pool: State<'_, SqlitePool>,
     sessionToken: String,
-) -> Result<Vec<CardAdminItem>, String> {
-    CardService::list_all_cards_admin(&pool, sessionToken).await
+    query: Option<CardListQuery>,
+) -> Result<Page<CardAdminItem>, AppError> {
+    CardService::list_all_cards_admin(&pool, sessionToken, query.unwrap_or_default()).await
 }
 
 #[tauri::command]
 pub async fn admin_update_card(
     pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
     input: UpdateCardInput,
-) -> Result<(), String> {
-    CardService::admin_update_card(&pool, input).await
+) -> Result<(), AppError> {
+    CardService::admin_update_card(&pool, &assets, input).await
 }
 
 #[tauri::command]
@@ -80,7 +153,95 @@ pub async fn admin_delete_card(
     pool: State<'_, SqlitePool>,
     sessionToken: String,
     id: i64,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     CardService::admin_delete_card(&pool, sessionToken, id).await
 }
 
+#[tauri::command]
+pub async fn list_cards_missing_images(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    session_token: String,
+) -> Result<Vec<MissingImageCard>, AppError> {
+    CardService::list_cards_missing_images(&pool, &assets, session_token).await
+}
+
+// JS: invoke("admin_import_cards", { sessionToken, filePath, dryRun: true })
+#[tauri::command]
+pub async fn admin_import_cards(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    session_token: String,
+    file_path: String,
+    dry_run: bool,
+) -> Result<ImportReport, AppError> {
+    ImportService::import_cards(&pool, &assets, session_token, file_path, dry_run).await
+}
+
+#[tauri::command]
+pub async fn list_card_history(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    card_id: i64,
+) -> Result<Vec<CardRevision>, AppError> {
+    CardService::list_card_history(&pool, session_token, card_id).await
+}
+
+#[tauri::command]
+pub async fn revert_card(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    session_token: String,
+    revision_id: i64,
+) -> Result<(), AppError> {
+    CardService::revert_card(&pool, &assets, session_token, revision_id).await
+}
+
+#[tauri::command]
+pub async fn list_archived_cards(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<ArchivedCard>, AppError> {
+    CardService::list_archived_cards(&pool, session_token).await
+}
+
+#[tauri::command]
+pub async fn restore_card(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    card_id: i64,
+) -> Result<(), AppError> {
+    CardService::restore_card(&pool, session_token, card_id).await
+}
+
+// JS: invoke("purge_card", { sessionToken, cardId, attempts: "DELETE" | "ANONYMIZE" })
+#[tauri::command]
+pub async fn purge_card(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    card_id: i64,
+    attempts: String,
+) -> Result<PurgeCardResult, AppError> {
+    CardService::purge_card(&pool, session_token, card_id, attempts).await
+}
+
+// JS: invoke("search_cards", { sessionToken, query: "fem", category: "BONES", limit: 20 })
+#[tauri::command]
+pub async fn search_cards(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    query: String,
+    category: Option<String>,
+    limit: Option<i64>,
+) -> Result<Vec<CardSearchHit>, AppError> {
+    SearchService::search_cards(&pool, session_token, query, category, limit).await
+}
+
+// Recomputes every card's difficulty now instead of waiting for the timer
+#[tauri::command]
+pub async fn refresh_card_difficulty(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<usize, AppError> {
+    DifficultyService::refresh_card_difficulty(&pool, session_token).await
+}
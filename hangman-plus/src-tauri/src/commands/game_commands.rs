@@ -8,8 +8,16 @@ use sqlx::SqlitePool;
 use tauri::State;
 use uuid::Uuid;
 
+use hangman_core::difficulty::DifficultyTier;
+use hangman_core::solver::{self, LetterScore};
+
 use crate::db;
+use crate::domain::dto::{BotRecord, BotRoundInput, BotRoundResult};
 use crate::domain::{card::Card, category::Category};
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+use crate::services::bot_service::BotService;
+use crate::services::difficulty_service;
 
 #[derive(Debug)]
 pub struct GamesState {
@@ -49,13 +57,23 @@ pub struct NextCardResponse {
 }
 
 // START
+// `card_difficulty` ("EASY" | "MEDIUM" | "HARD") picks cards by their computed
+// difficulty; it is separate from the EASY/HARD mistake rules of the game.
 #[tauri::command]
 pub async fn start_game(
     pool: State<'_, SqlitePool>,
     games: State<'_, GamesState>,
     category: Category,
-) -> Result<StartGameResponse, String> {
-    let mut deck = db::cards::get_cards_by_category(&pool, category).await?;
+    card_difficulty: Option<String>,
+) -> Result<StartGameResponse, AppError> {
+    let tier = match card_difficulty.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(raw) => Some(
+            DifficultyTier::parse(raw).ok_or("Card difficulty must be EASY, MEDIUM or HARD.")?,
+        ),
+        None => None,
+    };
+
+    let mut deck = difficulty_service::deck_for(&pool, category, tier).await?;
 
     if deck.is_empty() {
         return Ok(StartGameResponse {
@@ -93,12 +111,83 @@ pub async fn start_game(
     })
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LetterHint {
+    pub letter: String,
+    pub info_gain: f64,
+    pub hit_chance: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LetterSuggestion {
+    pub suggestions: Vec<LetterHint>, // best first
+    pub candidate_count: usize,       // cards that still fit the board
+}
+
+const HINT_COUNT: usize = 3;
+
+// HINT
+// JS: invoke("suggest_letter", { sessionToken, category: "BONES", language: "LAT", pattern: "f_m_r", guessed: ["f", "m", "r"] })
+// Signed-in players only, like the bot: the hint reads every playable term.
+// `pattern` is the board with "_" for hidden letters.
+#[tauri::command]
+pub async fn suggest_letter(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    category: Category,
+    language: String,
+    pattern: String,
+    guessed: Vec<String>,
+) -> Result<LetterSuggestion, AppError> {
+    AuthService::require_session(&pool, &session_token).await?;
+
+    let language = language.trim().to_uppercase();
+    let corpus = db::cards::get_terms(&pool, category, &language).await?;
+    let guessed: Vec<char> = guessed.iter().filter_map(|g| g.chars().next()).collect();
+
+    let candidate_count = solver::candidates(&pattern, &guessed, &corpus).len();
+    let suggestions = solver::rank_letters(&pattern, &guessed, &corpus)
+        .into_iter()
+        .take(HINT_COUNT)
+        .map(|LetterScore { letter, info_gain, hit_chance }| LetterHint {
+            letter: letter.to_string(),
+            info_gain,
+            hit_chance,
+        })
+        .collect();
+
+    Ok(LetterSuggestion {
+        suggestions,
+        candidate_count,
+    })
+}
+
+// BOT
+// JS: invoke("play_bot_round", { input: { sessionToken, gameId, cardId, language: "LAT", level: "MEDIUM", maxWrong: 6, playerWrong: 2, playerSolved: true } })
+#[tauri::command]
+pub async fn play_bot_round(
+    pool: State<'_, SqlitePool>,
+    input: BotRoundInput,
+) -> Result<BotRoundResult, AppError> {
+    BotService::play_bot_round(&pool, input).await
+}
+
+#[tauri::command]
+pub async fn get_bot_record(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<BotRecord>, AppError> {
+    BotService::get_bot_record(&pool, session_token).await
+}
+
 // NEXT
 #[tauri::command]
 pub async fn next_card(
     games: State<'_, GamesState>,
     game_id: Uuid,
-) -> Result<NextCardResponse, String> {
+) -> Result<NextCardResponse, AppError> {
     let mut map = games
         .games
         .lock()
@@ -132,7 +221,7 @@ pub async fn next_card(
 pub async fn reset_game(
     games: State<'_, GamesState>,
     game_id: Uuid,
-) -> Result<NextCardResponse, String> {
+) -> Result<NextCardResponse, AppError> {
     let mut map = games
         .games
         .lock()
@@ -155,7 +244,7 @@ pub async fn reset_game(
 
 // END
 #[tauri::command]
-pub async fn end_game(games: State<'_, GamesState>, game_id: Uuid) -> Result<(), String> {
+pub async fn end_game(games: State<'_, GamesState>, game_id: Uuid) -> Result<(), AppError> {
     games
         .games
         .lock()
@@ -164,7 +253,7 @@ pub async fn end_game(games: State<'_, GamesState>, game_id: Uuid) -> Result<(),
     Ok(())
 }
 
-fn run_total(games: &State<'_, GamesState>, game_id: Uuid) -> Result<usize, String> {
+fn run_total(games: &State<'_, GamesState>, game_id: Uuid) -> Result<usize, AppError> {
     let map = games
         .games
         .lock()
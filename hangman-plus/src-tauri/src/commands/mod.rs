@@ -1,8 +1,11 @@
+pub mod audit_commands;
 pub mod auth_commands;
 pub mod game_commands;
 pub mod card_commands;
+pub mod pack_commands;
 pub mod stats_commands;
 pub mod profile_commands;
+pub mod user_commands;
 
 
 
@@ -0,0 +1,81 @@
+use sqlx::SqlitePool;
+use tauri::State;
+
+use crate::domain::dto::{
+    PackExportResponse, PackImportReport, PackInspectResponse, SigningKeyResponse, TrustedPublisher,
+};
+use crate::errors::AppError;
+use crate::services::pack_service::PackService;
+use crate::utils::assets::AssetRoots;
+
+// JS: invoke("admin_export_pack", { sessionToken, cardIds: [1, 2], filePath, name, signingKey })
+#[tauri::command]
+pub async fn admin_export_pack(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    session_token: String,
+    card_ids: Vec<i64>,
+    file_path: String,
+    name: Option<String>,
+    signing_key: Option<String>,
+) -> Result<PackExportResponse, AppError> {
+    PackService::export_pack(&pool, &assets, session_token, card_ids, file_path, name, signing_key)
+        .await
+}
+
+#[tauri::command]
+pub async fn admin_inspect_pack(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    file_path: String,
+) -> Result<PackInspectResponse, AppError> {
+    PackService::inspect_pack(&pool, session_token, file_path).await
+}
+
+// approve = false leaves incoming cards in the PENDING queue;
+// packs not signed by a trusted publisher always go to PENDING
+#[tauri::command]
+pub async fn admin_import_pack(
+    pool: State<'_, SqlitePool>,
+    assets: State<'_, AssetRoots>,
+    session_token: String,
+    file_path: String,
+    approve: bool,
+) -> Result<PackImportReport, AppError> {
+    PackService::import_pack(&pool, &assets, session_token, file_path, approve).await
+}
+
+#[tauri::command]
+pub async fn list_trusted_publishers(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<TrustedPublisher>, AppError> {
+    PackService::list_trusted_publishers(&pool, session_token).await
+}
+
+#[tauri::command]
+pub async fn add_trusted_publisher(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    name: String,
+    public_key: String,
+) -> Result<i64, AppError> {
+    PackService::add_trusted_publisher(&pool, session_token, name, public_key).await
+}
+
+#[tauri::command]
+pub async fn remove_trusted_publisher(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    id: i64,
+) -> Result<(), AppError> {
+    PackService::remove_trusted_publisher(&pool, session_token, id).await
+}
+
+#[tauri::command]
+pub async fn generate_signing_key(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<SigningKeyResponse, AppError> {
+    PackService::generate_signing_key(&pool, session_token).await
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use sqlx::SqlitePool;
 use tauri::State;
 
+use crate::errors::AppError;
 use crate::services::profile_service::ProfileService;
 
 #[derive(Debug, Serialize)]
@@ -37,7 +38,7 @@ pub struct ChangePasswordRequest {
 pub async fn get_profile(
     pool: State<'_, SqlitePool>,
     session_token: String,
-) -> Result<ProfileResponse, String> {
+) -> Result<ProfileResponse, AppError> {
     let row = ProfileService::get_profile(&pool, session_token).await?;
     Ok(ProfileResponse {
         id: row.id,
@@ -53,7 +54,7 @@ pub async fn get_profile(
 pub async fn update_profile(
     pool: State<'_, SqlitePool>,
     req: UpdateProfileRequest,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     ProfileService::update_profile(
         &pool,
         req.session_token,
@@ -69,6 +70,6 @@ pub async fn update_profile(
 pub async fn change_password(
     pool: State<'_, SqlitePool>,
     req: ChangePasswordRequest,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     ProfileService::change_password(&pool, req.session_token, req.old_password, req.new_password).await
 }
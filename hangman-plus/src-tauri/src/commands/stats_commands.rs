@@ -2,12 +2,20 @@ use serde::{Deserialize, Serialize};
 use sqlx::{Row, SqlitePool};
 use tauri::State;
 
+use crate::config::AppConfig;
+use crate::domain::dto::{CardAnalyticsDetail, CardAnalyticsQuery, CardAnalyticsRow, Page, UserRating};
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::services::analytics_service::AnalyticsService;
+use crate::services::auth_service::AuthService;
+use crate::services::rating_service::{self, RatingService};
+
 // -------------------- LOG ATTEMPT --------------------
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogAttemptRequest {
-    pub user_id: i64,
+    pub session_token: String, // the attempt is recorded for the session's user
     pub card_id: i64,
     pub is_won: bool,
 
@@ -17,57 +25,70 @@ pub struct LogAttemptRequest {
 
     pub wrong_count: Option<i64>,
     pub max_wrong: Option<i64>,
+
+    #[serde(default)]
+    pub wrong_letters: Option<String>, // letters guessed wrong, e.g. "qxz"
 }
 
-async fn ensure_table(pool: &SqlitePool) -> Result<(), String> {
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS card_attempts (
-          id INTEGER PRIMARY KEY AUTOINCREMENT,
-          user_id INTEGER NOT NULL,
-          card_id INTEGER NOT NULL,
-          is_won INTEGER NOT NULL CHECK (is_won IN (0, 1)),
-          category TEXT,
-          language TEXT,
-          difficulty TEXT,
-          wrong_count INTEGER,
-          max_wrong INTEGER,
-          played_at TEXT NOT NULL DEFAULT (datetime('now'))
-        );
-
-        CREATE INDEX IF NOT EXISTS idx_card_attempts_user ON card_attempts(user_id);
-        CREATE INDEX IF NOT EXISTS idx_card_attempts_user_won ON card_attempts(user_id, is_won);
-        CREATE INDEX IF NOT EXISTS idx_card_attempts_user_time ON card_attempts(user_id, played_at);
-        "#,
-    )
-    .execute(pool)
-    .await
-    .map_err(|e| format!("Init card_attempts failed: {e}"))?;
+// Stats of another user are only for staff with stats access
+async fn require_stats_of(pool: &SqlitePool, session_token: &str, user_id: i64) -> Result<(), AppError> {
+    AuthService::require_permission(pool, session_token, Permission::StatsView).await?;
+
+    let exists = sqlx::query_scalar::<_, i64>(r#"SELECT COUNT(*) FROM users WHERE id = ?1"#)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
 
+    if exists == 0 {
+        return Err(AppError::new("USER_NOT_FOUND", "User not found."));
+    }
     Ok(())
 }
 
+// Keeps each letter once, lowercased, so counting per attempt stays honest
+fn clean_wrong_letters(raw: Option<String>) -> Option<String> {
+    let mut letters: Vec<char> = raw?
+        .chars()
+        .filter(|c| c.is_alphabetic())
+        .flat_map(|c| c.to_lowercase())
+        .collect();
+    letters.sort_unstable();
+    letters.dedup();
+
+    if letters.is_empty() {
+        None
+    } else {
+        Some(letters.into_iter().collect())
+    }
+}
+
 #[tauri::command]
 pub async fn log_card_attempt(
     pool: State<'_, SqlitePool>,
     req: LogAttemptRequest,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
+    let (user_id, _role) = AuthService::require_session_user(&pool, &req.session_token).await?;
+
     println!(
         "[log_card_attempt] user_id={} card_id={} is_won={}",
-        req.user_id, req.card_id, req.is_won
+        user_id, req.card_id, req.is_won
     );
 
-    ensure_table(&pool).await?;
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
 
     sqlx::query(
         r#"
         INSERT INTO card_attempts
-          (user_id, card_id, is_won, category, language, difficulty, wrong_count, max_wrong)
+          (user_id, card_id, is_won, category, language, difficulty, wrong_count, max_wrong, wrong_letters)
         VALUES
-          (?, ?, ?, ?, ?, ?, ?, ?)
+          (?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
     )
-    .bind(req.user_id)
+    .bind(user_id)
     .bind(req.card_id)
     .bind(if req.is_won { 1 } else { 0 })
     .bind(req.category)
@@ -75,10 +96,17 @@ pub async fn log_card_attempt(
     .bind(req.difficulty)
     .bind(req.wrong_count)
     .bind(req.max_wrong)
-    .execute(&*pool)
+    .bind(clean_wrong_letters(req.wrong_letters))
+    .execute(&mut *tx)
     .await
     .map_err(|e| format!("Insert attempt failed: {e}"))?;
 
+    rating_service::record_attempt(&mut tx, user_id, req.card_id, req.is_won).await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Attempt commit failed: {e}"))?;
+
     Ok(())
 }
 
@@ -103,16 +131,29 @@ pub struct UserStatsResponse {
     pub missed_cards: Vec<MissedCard>,
 }
 
+// JS: invoke("get_user_stats", { sessionToken })
 #[tauri::command]
 pub async fn get_user_stats(
     pool: State<'_, SqlitePool>,
-    userId: i64, // JS: invoke("get_user_stats", { userId })
-) -> Result<UserStatsResponse, String> {
-    let user_id = userId;
+    session_token: String,
+) -> Result<UserStatsResponse, AppError> {
+    let (user_id, _role) = AuthService::require_session_user(&pool, &session_token).await?;
+    user_stats(&pool, user_id).await
+}
 
-    println!("[get_user_stats] called for user {}", user_id);
+// JS: invoke("get_user_stats_for", { sessionToken, userId })
+#[tauri::command]
+pub async fn get_user_stats_for(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    user_id: i64,
+) -> Result<UserStatsResponse, AppError> {
+    require_stats_of(&pool, &session_token, user_id).await?;
+    user_stats(&pool, user_id).await
+}
 
-    ensure_table(&pool).await?;
+async fn user_stats(pool: &SqlitePool, user_id: i64) -> Result<UserStatsResponse, AppError> {
+    println!("[get_user_stats] called for user {}", user_id);
 
     let totals = sqlx::query(
         r#"
@@ -124,7 +165,7 @@ pub async fn get_user_stats(
         "#,
     )
     .bind(user_id)
-    .fetch_one(&*pool)
+    .fetch_one(pool)
     .await
     .map_err(|e| format!("Fetch totals failed: {e}"))?;
 
@@ -149,7 +190,7 @@ pub async fn get_user_stats(
         "#,
     )
     .bind(user_id)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch missed cards failed: {e}"))?;
 
@@ -212,16 +253,31 @@ pub struct UserAnalyticsResponse {
 
 // -------------------- ANALYTICS COMMAND --------------------
 
+// JS: invoke("get_user_analytics", { sessionToken, days: 14 })
 #[tauri::command]
 pub async fn get_user_analytics(
     pool: State<'_, SqlitePool>,
-    userId: i64,
-    days: Option<i64>, // JS: invoke("get_user_analytics", { userId, days: 14 })
-) -> Result<UserAnalyticsResponse, String> {
-    let user_id = userId;
-    let days = days.unwrap_or(14).clamp(1, 365);
+    session_token: String,
+    days: Option<i64>,
+) -> Result<UserAnalyticsResponse, AppError> {
+    let (user_id, _role) = AuthService::require_session_user(&pool, &session_token).await?;
+    user_analytics(&pool, user_id, days).await
+}
 
-    ensure_table(&pool).await?;
+// JS: invoke("get_user_analytics_for", { sessionToken, userId, days: 14 })
+#[tauri::command]
+pub async fn get_user_analytics_for(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    user_id: i64,
+    days: Option<i64>,
+) -> Result<UserAnalyticsResponse, AppError> {
+    require_stats_of(&pool, &session_token, user_id).await?;
+    user_analytics(&pool, user_id, days).await
+}
+
+async fn user_analytics(pool: &SqlitePool, user_id: i64, days: Option<i64>) -> Result<UserAnalyticsResponse, AppError> {
+    let days = days.unwrap_or(14).clamp(1, 365);
 
     // 1) DAILY (attempts/wins/losses) last N days
     let daily_rows = sqlx::query(
@@ -240,7 +296,7 @@ pub async fn get_user_analytics(
     )
     .bind(user_id)
     .bind(days)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch daily analytics failed: {e}"))?;
 
@@ -266,7 +322,7 @@ pub async fn get_user_analytics(
         "#,
     )
     .bind(user_id)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch missed_by_category failed: {e}"))?;
 
@@ -289,7 +345,7 @@ pub async fn get_user_analytics(
         "#,
     )
     .bind(user_id)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch attempts_by_category failed: {e}"))?;
 
@@ -316,7 +372,7 @@ pub async fn get_user_analytics(
         "#,
     )
     .bind(user_id)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch difficulty analytics failed: {e}"))?;
 
@@ -354,7 +410,7 @@ pub async fn get_user_analytics(
         "#,
     )
     .bind(user_id)
-    .fetch_all(&*pool)
+    .fetch_all(pool)
     .await
     .map_err(|e| format!("Fetch wrong_count_dist failed: {e}"))?;
 
@@ -374,3 +430,42 @@ pub async fn get_user_analytics(
         wrong_count_dist,
     })
 }
+
+// -------------------- CARD ANALYTICS (ADMIN) --------------------
+
+// JS: invoke("list_card_analytics", { sessionToken, query: { flaggedOnly: true, sort: "HARDEST" } })
+#[tauri::command]
+pub async fn list_card_analytics(
+    pool: State<'_, SqlitePool>,
+    config: State<'_, AppConfig>,
+    session_token: String,
+    query: Option<CardAnalyticsQuery>,
+) -> Result<Page<CardAnalyticsRow>, AppError> {
+    AnalyticsService::list_card_analytics(
+        &pool,
+        &config.difficulty_bands,
+        session_token,
+        query.unwrap_or_default(),
+    )
+    .await
+}
+
+#[tauri::command]
+pub async fn get_card_analytics(
+    pool: State<'_, SqlitePool>,
+    config: State<'_, AppConfig>,
+    session_token: String,
+    card_id: i64,
+) -> Result<CardAnalyticsDetail, AppError> {
+    AnalyticsService::get_card_analytics(&pool, &config.difficulty_bands, session_token, card_id).await
+}
+
+// -------------------- RATINGS --------------------
+
+#[tauri::command]
+pub async fn get_my_ratings(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<UserRating>, AppError> {
+    RatingService::get_my_ratings(&pool, session_token).await
+}
@@ -0,0 +1,35 @@
+use sqlx::SqlitePool;
+use tauri::State;
+
+use crate::domain::dto::UserAdminItem;
+use crate::errors::AppError;
+use crate::services::user_service::UserService;
+
+#[tauri::command]
+pub async fn list_users(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+) -> Result<Vec<UserAdminItem>, AppError> {
+    UserService::list_users(&pool, session_token).await
+}
+
+// JS: invoke("set_user_role", { sessionToken, userId, role: "MODERATOR" })
+#[tauri::command]
+pub async fn set_user_role(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    user_id: i64,
+    role: String,
+) -> Result<UserAdminItem, AppError> {
+    UserService::set_user_role(&pool, session_token, user_id, role).await
+}
+
+// JS: invoke("unlock_account", { sessionToken, userId })
+#[tauri::command]
+pub async fn unlock_account(
+    pool: State<'_, SqlitePool>,
+    session_token: String,
+    user_id: i64,
+) -> Result<UserAdminItem, AppError> {
+    UserService::unlock_account(&pool, session_token, user_id).await
+}
@@ -0,0 +1,218 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+// Optional settings file (<app data>/config.json). Every key has a default,
+// so a missing file or a partial one is fine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AppConfig {
+    pub difficulty_bands: DifficultyBands,
+    pub difficulty_refresh_minutes: u64, // how often card difficulty is recomputed
+    pub sessions: SessionPolicy,
+    pub login_throttle: LoginThrottle,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            difficulty_bands: DifficultyBands::default(),
+            difficulty_refresh_minutes: 60,
+            sessions: SessionPolicy::default(),
+            login_throttle: LoginThrottle::default(),
+        }
+    }
+}
+
+// A card is flagged when its win rate across all players leaves the band.
+// Cards with fewer than `min_attempts` plays are never flagged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DifficultyBands {
+    pub min_attempts: i64,
+    pub min_win_rate: f64, // below -> TOO_HARD
+    pub max_win_rate: f64, // above -> TOO_EASY
+}
+
+impl Default for DifficultyBands {
+    fn default() -> Self {
+        Self {
+            min_attempts: 10,
+            min_win_rate: 0.3,
+            max_win_rate: 0.95,
+        }
+    }
+}
+
+impl DifficultyBands {
+    fn validate(&self) -> Result<(), String> {
+        let in_unit = |v: f64| (0.0..=1.0).contains(&v);
+
+        if self.min_attempts < 1 {
+            return Err("difficultyBands.minAttempts must be at least 1.".into());
+        }
+        if !in_unit(self.min_win_rate) || !in_unit(self.max_win_rate) {
+            return Err("difficultyBands win rates must be between 0 and 1.".into());
+        }
+        if self.min_win_rate >= self.max_win_rate {
+            return Err("difficultyBands.minWinRate must be below maxWinRate.".into());
+        }
+        Ok(())
+    }
+}
+
+// Limits written onto each session at login. A session ends `absolute_hours`
+// after login, or after `idle_minutes` without any command using it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SessionPolicy {
+    pub absolute_hours: i64,
+    pub idle_minutes: i64,
+}
+
+impl Default for SessionPolicy {
+    fn default() -> Self {
+        Self {
+            absolute_hours: 72,
+            idle_minutes: 240,
+        }
+    }
+}
+
+impl SessionPolicy {
+    pub fn absolute_secs(&self) -> i64 {
+        self.absolute_hours * 3600
+    }
+
+    pub fn idle_secs(&self) -> i64 {
+        self.idle_minutes * 60
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.absolute_hours < 1 || self.idle_minutes < 1 {
+            return Err("sessions.absoluteHours and sessions.idleMinutes must be at least 1.".into());
+        }
+        if self.idle_secs() > self.absolute_secs() {
+            return Err("sessions.idleMinutes cannot be longer than absoluteHours.".into());
+        }
+        Ok(())
+    }
+}
+
+// Failed logins per account: the first `free_attempts` cost nothing, then each
+// one doubles the wait (from `base_delay_secs` up to `max_delay_secs`).
+// `lockout_threshold` failures lock the account for `lockout_minutes`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LoginThrottle {
+    pub free_attempts: i64,
+    pub base_delay_secs: i64,
+    pub max_delay_secs: i64,
+    pub lockout_threshold: i64,
+    pub lockout_minutes: i64,
+}
+
+impl Default for LoginThrottle {
+    fn default() -> Self {
+        Self {
+            free_attempts: 3,
+            base_delay_secs: 2,
+            max_delay_secs: 300,
+            lockout_threshold: 10,
+            lockout_minutes: 15,
+        }
+    }
+}
+
+impl LoginThrottle {
+    // wait before the next try, after `failed` failures in a row
+    pub fn delay_after(&self, failed: i64) -> i64 {
+        if failed < self.free_attempts {
+            return 0;
+        }
+        let doublings = (failed - self.free_attempts).min(30) as u32;
+        self.base_delay_secs
+            .saturating_mul(1 << doublings)
+            .min(self.max_delay_secs)
+    }
+
+    pub fn lockout_secs(&self) -> i64 {
+        self.lockout_minutes * 60
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.free_attempts < 0 || self.base_delay_secs < 0 {
+            return Err("loginThrottle.freeAttempts and baseDelaySecs cannot be negative.".into());
+        }
+        if self.max_delay_secs < self.base_delay_secs {
+            return Err("loginThrottle.maxDelaySecs must be at least baseDelaySecs.".into());
+        }
+        if self.lockout_threshold < 1 || self.lockout_minutes < 1 {
+            return Err("loginThrottle.lockoutThreshold and lockoutMinutes must be at least 1.".into());
+        }
+        Ok(())
+    }
+}
+
+impl AppConfig {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let text = fs::read_to_string(path).map_err(|e| format!("Read config failed: {e}"))?;
+        let config: AppConfig =
+            serde_json::from_str(&text).map_err(|e| format!("Bad config {}: {e}", path.display()))?;
+
+        config.difficulty_bands.validate()?;
+        config.sessions.validate()?;
+        config.login_throttle.validate()?;
+        if config.difficulty_refresh_minutes == 0 {
+            return Err("difficultyRefreshMinutes must be at least 1.".into());
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_keys_fall_back_to_defaults() {
+        let c: AppConfig = serde_json::from_str(r#"{"difficultyBands":{"minWinRate":0.5}}"#).unwrap();
+        assert_eq!(c.difficulty_bands.min_win_rate, 0.5);
+        assert_eq!(c.difficulty_bands.min_attempts, 10);
+        assert!(c.difficulty_bands.validate().is_ok());
+    }
+
+    #[test]
+    fn inverted_band_is_rejected() {
+        let bands = DifficultyBands {
+            min_win_rate: 0.9,
+            max_win_rate: 0.4,
+            ..Default::default()
+        };
+        assert!(bands.validate().is_err());
+    }
+
+    #[test]
+    fn idle_window_must_fit_in_absolute_lifetime() {
+        let policy = SessionPolicy {
+            absolute_hours: 1,
+            idle_minutes: 90,
+        };
+        assert!(policy.validate().is_err());
+        assert!(SessionPolicy::default().validate().is_ok());
+    }
+
+    #[test]
+    fn login_delay_doubles_after_free_attempts_up_to_the_cap() {
+        let t = LoginThrottle::default();
+        let delays: Vec<i64> = (1..=10).map(|n| t.delay_after(n)).collect();
+        assert_eq!(delays, vec![0, 0, 2, 4, 8, 16, 32, 64, 128, 256]);
+        assert_eq!(t.delay_after(40), 300);
+        assert!(t.validate().is_ok());
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Serialize;
+use serde_json::Value;
+use sqlx::SqliteConnection;
+use time::OffsetDateTime;
+
+// Before/after state for an entry; None (or JSON null) is stored as NULL
+pub fn json<T: Serialize>(value: &T) -> Option<Value> {
+    serde_json::to_value(value).ok().filter(|v| !v.is_null())
+}
+
+// Appends one entry. Run it on the same transaction as the change itself
+// so a rolled back action leaves no entry behind.
+pub async fn record(
+    conn: &mut SqliteConnection,
+    actor_id: i64,
+    action: &str,
+    target_type: &str,
+    target_id: Option<i64>,
+    before: Option<Value>,
+    after: Option<Value>,
+) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        INSERT INTO audit_log (actor_id, action, target_type, target_id, before_values, after_values, created_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+    )
+    .bind(actor_id)
+    .bind(action)
+    .bind(target_type)
+    .bind(target_id)
+    .bind(before.map(|v| v.to_string()))
+    .bind(after.map(|v| v.to_string()))
+    .bind(OffsetDateTime::now_utc().unix_timestamp())
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Audit log insert failed: {e}"))?;
+
+    Ok(())
+}
@@ -1,13 +1,11 @@
 use sqlx::SqlitePool;
 
-pub async fn init(pool: &SqlitePool) -> Result<(), String> {
- 
-    sqlx::query(
-        r#"
+// card_id is NULL for attempts kept after their card was purged (see purge_card)
+const CREATE_TABLE: &str = r#"
         CREATE TABLE IF NOT EXISTS card_attempts (
           id INTEGER PRIMARY KEY AUTOINCREMENT,
           user_id INTEGER NOT NULL,
-          card_id INTEGER NOT NULL,
+          card_id INTEGER NULL,
 
           is_won INTEGER NOT NULL CHECK (is_won IN (0, 1)),
 
@@ -16,18 +14,137 @@ pub async fn init(pool: &SqlitePool) -> Result<(), String> {
           difficulty TEXT,
           wrong_count INTEGER,
           max_wrong INTEGER,
+          wrong_letters TEXT,
 
           played_at TEXT NOT NULL DEFAULT (datetime('now'))
         );
+        "#;
+
+pub async fn init(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query(CREATE_TABLE)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Init card_attempts failed: {e}"))?;
 
+    // letters guessed wrong in the round, e.g. "qxz" (for per-card analytics)
+    let _ = sqlx::query(r#"ALTER TABLE card_attempts ADD COLUMN wrong_letters TEXT;"#)
+        .execute(pool)
+        .await;
+
+    allow_null_card_id(pool).await?;
+
+    sqlx::query(
+        r#"
         CREATE INDEX IF NOT EXISTS idx_card_attempts_user ON card_attempts(user_id);
         CREATE INDEX IF NOT EXISTS idx_card_attempts_user_won ON card_attempts(user_id, is_won);
         CREATE INDEX IF NOT EXISTS idx_card_attempts_user_time ON card_attempts(user_id, played_at);
+        CREATE INDEX IF NOT EXISTS idx_card_attempts_card ON card_attempts(card_id);
         "#,
     )
     .execute(pool)
     .await
-    .map_err(|e| format!("Init card_attempts failed: {e}"))?;
+    .map_err(|e| format!("Init card_attempts indexes failed: {e}"))?;
+
+    Ok(())
+}
+
+// Older databases have `card_id NOT NULL` and mark purged cards with card_id = 0.
+// SQLite can't drop a NOT NULL, so the table is copied into the new shape once.
+async fn allow_null_card_id(pool: &SqlitePool) -> Result<(), String> {
+    let not_null: Option<bool> = sqlx::query_scalar(
+        r#"SELECT "notnull" FROM pragma_table_info('card_attempts') WHERE name = 'card_id'"#,
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("Inspect card_attempts failed: {e}"))?;
+
+    if not_null != Some(true) {
+        return Ok(());
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    sqlx::query(r#"ALTER TABLE card_attempts RENAME TO card_attempts_old;"#)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Migrate card_attempts failed: {e}"))?;
+
+    // the old indexes go with the old table and are created again by init
+    sqlx::query(CREATE_TABLE)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Migrate card_attempts failed: {e}"))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO card_attempts
+          (id, user_id, card_id, is_won, category, language, difficulty, wrong_count, max_wrong, wrong_letters, played_at)
+        SELECT
+          id, user_id, NULLIF(card_id, 0), is_won, category, language, difficulty, wrong_count, max_wrong, wrong_letters, played_at
+        FROM card_attempts_old;
+
+        DROP TABLE card_attempts_old;
+        "#,
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Migrate card_attempts failed: {e}"))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Migrate card_attempts failed: {e}"))?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SessionPolicy;
+
+    #[tokio::test]
+    async fn old_not_null_table_is_migrated_and_zero_ids_become_null() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        std::fs::write(&db_path, b"").unwrap();
+
+        let pool = SqlitePool::connect(&format!("sqlite://{}", db_path.display())).await.unwrap();
+        sqlx::query(
+            r#"
+            CREATE TABLE card_attempts (
+              id INTEGER PRIMARY KEY AUTOINCREMENT,
+              user_id INTEGER NOT NULL,
+              card_id INTEGER NOT NULL,
+              is_won INTEGER NOT NULL CHECK (is_won IN (0, 1)),
+              category TEXT,
+              language TEXT,
+              difficulty TEXT,
+              wrong_count INTEGER,
+              max_wrong INTEGER,
+              played_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            INSERT INTO card_attempts (user_id, card_id, is_won) VALUES (1, 0, 1), (1, 7, 0);
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+
+        let pool = crate::db::init_db(db_path, &SessionPolicy::default()).await.unwrap();
+
+        let card_ids: Vec<Option<i64>> = sqlx::query_scalar("SELECT card_id FROM card_attempts ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(card_ids, vec![None, Some(7)]);
+
+        sqlx::query("INSERT INTO card_attempts (user_id, card_id, is_won, wrong_letters) VALUES (1, NULL, 0, 'q')")
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,56 @@
+use sqlx::types::Json;
+use sqlx::SqliteConnection;
+use time::OffsetDateTime;
+
+use crate::domain::dto::CardSnapshot;
+
+// Current state of a card, None if it doesn't exist
+pub async fn snapshot(
+    conn: &mut SqliteConnection,
+    card_id: i64,
+) -> Result<Option<CardSnapshot>, String> {
+    sqlx::query_as::<_, CardSnapshot>(
+        r#"
+        SELECT category, english, latin, image_path, region, synonyms, description, status
+        FROM cards
+        WHERE id = ?1
+        "#,
+    )
+    .bind(card_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| format!("DB error: {e}"))
+}
+
+// Stores one history entry. The "new" side is read back from the card,
+// so call this after the change, on the same connection/transaction.
+// Returns that new snapshot (None after a purge).
+pub async fn record(
+    conn: &mut SqliteConnection,
+    card_id: i64,
+    action: &str,
+    editor_id: i64,
+    old: Option<&CardSnapshot>,
+    note: Option<&str>,
+) -> Result<Option<CardSnapshot>, String> {
+    let new = snapshot(&mut *conn, card_id).await?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO card_revisions (card_id, action, editor_id, created_at, old_values, new_values, note)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+        "#,
+    )
+    .bind(card_id)
+    .bind(action)
+    .bind(editor_id)
+    .bind(OffsetDateTime::now_utc().unix_timestamp())
+    .bind(old.map(Json))
+    .bind(new.as_ref().map(Json))
+    .bind(note)
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Revision insert failed: {e}"))?;
+
+    Ok(new)
+}
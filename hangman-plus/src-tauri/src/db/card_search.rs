@@ -0,0 +1,90 @@
+use sqlx::SqlitePool;
+
+// Full-text index over the card terms. It is an external-content FTS5 table,
+// so only the index lives here and triggers keep it in step with `cards`.
+// remove_diacritics 2 makes "Os ilium" match "ílium" and the other way round.
+pub async fn init(pool: &SqlitePool) -> Result<(), String> {
+    let existed: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'cards_fts'",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    sqlx::query(
+        r#"
+        CREATE VIRTUAL TABLE IF NOT EXISTS cards_fts USING fts5(
+          english,
+          latin,
+          synonyms,
+          description,
+          content = 'cards',
+          content_rowid = 'id',
+          tokenize = 'unicode61 remove_diacritics 2'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS cards_fts_insert AFTER INSERT ON cards
+        BEGIN
+          INSERT INTO cards_fts (rowid, english, latin, synonyms, description)
+          VALUES (new.id, new.english, new.latin, new.synonyms, new.description);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS cards_fts_delete AFTER DELETE ON cards
+        BEGIN
+          INSERT INTO cards_fts (cards_fts, rowid, english, latin, synonyms, description)
+          VALUES ('delete', old.id, old.english, old.latin, old.synonyms, old.description);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS cards_fts_update
+        AFTER UPDATE OF english, latin, synonyms, description ON cards
+        BEGIN
+          INSERT INTO cards_fts (cards_fts, rowid, english, latin, synonyms, description)
+          VALUES ('delete', old.id, old.english, old.latin, old.synonyms, old.description);
+          INSERT INTO cards_fts (rowid, english, latin, synonyms, description)
+          VALUES (new.id, new.english, new.latin, new.synonyms, new.description);
+        END;
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Init cards_fts failed: {e}"))?;
+
+    // cards written before the index existed
+    if existed == 0 {
+        sqlx::query("INSERT INTO cards_fts (cards_fts) VALUES ('rebuild')")
+            .execute(pool)
+            .await
+            .map_err(|e| format!("Build cards_fts failed: {e}"))?;
+    }
+
+    Ok(())
+}
+
+// Turns user input into an FTS5 MATCH expression: every word is a quoted
+// prefix term and all of them must match. Operators typed by the user
+// (AND, NEAR, quotes, ...) end up as plain words, so input can't break the query.
+pub fn match_query(input: &str) -> Option<String> {
+    let terms: Vec<String> = input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| format!("\"{w}\"*"))
+        .collect();
+
+    if terms.is_empty() {
+        None
+    } else {
+        Some(terms.join(" "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_become_prefix_terms() {
+        assert_eq!(match_query("  os ili "), Some(r#""os"* "ili"*"#.to_string()));
+        assert_eq!(match_query(r#"fem" OR NEAR(x"#), Some(r#""fem"* "OR"* "NEAR"* "x"*"#.to_string()));
+        assert_eq!(match_query(" -*\" "), None);
+    }
+}
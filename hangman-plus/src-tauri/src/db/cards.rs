@@ -1,6 +1,6 @@
-use sqlx::{Row, SqlitePool};
+use sqlx::{types::Json, Row, SqlitePool};
 
-use crate::domain::{card::Card, category::Category};
+use crate::domain::{card::Card, category::Category, region::Region};
 
 pub async fn get_cards_by_category(
     pool: &SqlitePool,
@@ -8,10 +8,11 @@ pub async fn get_cards_by_category(
 ) -> Result<Vec<Card>, String> {
     let rows = sqlx::query(
         r#"
-        SELECT id, category, english, latin, image_path
+        SELECT id, category, english, latin, image_path, region, difficulty
         FROM cards
         WHERE category = ?
           AND status = 'APPROVED'
+          AND archived_at IS NULL
         "#,
     )
     .bind(category.as_str())
@@ -30,14 +31,73 @@ pub async fn get_cards_by_category(
             other => return Err(format!("Unknown category in DB: {other}")),
         };
 
+        // a malformed region only costs the card its diagram, not the whole category
+        let id = r.get::<i64, _>("id");
+        let region = match r.try_get::<Option<Json<Region>>, _>("region") {
+            Ok(region) => region.map(|j| j.0),
+            Err(e) => {
+                eprintln!("Ignoring bad region on card {id}: {e}");
+                None
+            }
+        };
+
         cards.push(Card {
-            id: r.get::<i64, _>("id"),
+            id,
             category: cat,
             english: r.get::<String, _>("english"),
             latin: r.get::<String, _>("latin"),
             image_path: r.get::<String, _>("image_path"),
+            region,
+            difficulty: r.get::<Option<f64>, _>("difficulty"),
         });
     }
 
     Ok(cards)
 }
+
+// Terms of the playable cards in one category, in the given language ("EN" | "LAT")
+pub async fn get_terms(pool: &SqlitePool, category: Category, language: &str) -> Result<Vec<String>, String> {
+    let column = match language {
+        "EN" => "english",
+        "LAT" => "latin",
+        other => return Err(format!("Unknown language: {other}")),
+    };
+
+    sqlx::query_scalar::<_, String>(&format!(
+        r#"
+        SELECT {column}
+        FROM cards
+        WHERE category = ?
+          AND status = 'APPROVED'
+          AND archived_at IS NULL
+        "#
+    ))
+    .bind(category.as_str())
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Fetch terms failed: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::temp_pool;
+
+    #[tokio::test]
+    async fn malformed_region_is_dropped_not_fatal() {
+        let (_dir, pool) = temp_pool().await;
+        sqlx::query(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, region, status, created_at)
+            VALUES ('BONES', 'Femur', 'Femur', '/cards/bones/femur.png', '{not json', 'APPROVED', 0)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let cards = get_cards_by_category(&pool, Category::Bones).await.unwrap();
+        assert_eq!(cards.len(), 1);
+        assert!(cards[0].region.is_none());
+    }
+}
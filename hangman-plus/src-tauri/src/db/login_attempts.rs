@@ -0,0 +1,162 @@
+use sqlx::SqlitePool;
+
+use crate::config::LoginThrottle;
+
+// Failed logins per account ("user:<id>") or, for identifiers that match no
+// account, per typed identifier ("id:<lowercased>"). Deleted on success.
+pub async fn init(pool: &SqlitePool) -> Result<(), String> {
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS login_attempts (
+          key TEXT PRIMARY KEY,
+          user_id INTEGER NULL,
+          failed_count INTEGER NOT NULL,
+          last_failed_at INTEGER NOT NULL,
+          next_allowed_at INTEGER NOT NULL,
+          locked_until INTEGER NULL,
+          FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE CASCADE
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_login_attempts_user ON login_attempts(user_id);
+        "#,
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Init login_attempts failed: {e}"))?;
+
+    Ok(())
+}
+
+#[derive(Debug, Default, sqlx::FromRow)]
+pub struct LoginAttempts {
+    pub failed_count: i64,
+    pub next_allowed_at: i64,
+    pub locked_until: Option<i64>,
+}
+
+pub fn key_for(user_id: Option<i64>, identifier: &str) -> String {
+    match user_id {
+        Some(id) => format!("user:{id}"),
+        None => format!("id:{}", identifier.trim().to_lowercase()),
+    }
+}
+
+pub async fn get(pool: &SqlitePool, key: &str) -> Result<Option<LoginAttempts>, String> {
+    sqlx::query_as::<_, LoginAttempts>(
+        r#"SELECT failed_count, next_allowed_at, locked_until FROM login_attempts WHERE key = ?1"#,
+    )
+    .bind(key)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))
+}
+
+// Counts one more failure and returns the new state. Reaching the threshold
+// locks the key and starts the count over once the lock ends.
+// The count goes up in SQL inside a transaction, so concurrent failures for
+// the same key are all counted: the upsert takes SQLite's write lock and the
+// next attempt waits until this one has committed.
+pub async fn record_failure(
+    pool: &SqlitePool,
+    key: &str,
+    user_id: Option<i64>,
+    policy: &LoginThrottle,
+    now: i64,
+) -> Result<LoginAttempts, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let failed = sqlx::query_scalar::<_, i64>(
+        r#"
+        INSERT INTO login_attempts (key, user_id, failed_count, last_failed_at, next_allowed_at, locked_until)
+        VALUES (?1, ?2, 1, ?3, ?3, NULL)
+        ON CONFLICT(key) DO UPDATE SET
+          failed_count = failed_count + 1,
+          last_failed_at = excluded.last_failed_at
+        RETURNING failed_count
+        "#,
+    )
+    .bind(key)
+    .bind(user_id)
+    .bind(now)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| format!("Record failed login failed: {e}"))?;
+
+    let state = if failed >= policy.lockout_threshold {
+        let until = now + policy.lockout_secs();
+        LoginAttempts {
+            failed_count: 0,
+            next_allowed_at: until,
+            locked_until: Some(until),
+        }
+    } else {
+        LoginAttempts {
+            failed_count: failed,
+            next_allowed_at: now + policy.delay_after(failed),
+            locked_until: None,
+        }
+    };
+
+    sqlx::query(
+        r#"
+        UPDATE login_attempts
+        SET failed_count = ?2,
+            next_allowed_at = ?3,
+            locked_until = ?4
+        WHERE key = ?1
+        "#,
+    )
+    .bind(key)
+    .bind(state.failed_count)
+    .bind(state.next_allowed_at)
+    .bind(state.locked_until)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Record failed login failed: {e}"))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Commit failed: {e}"))?;
+
+    Ok(state)
+}
+
+pub async fn clear(pool: &SqlitePool, key: &str) -> Result<(), String> {
+    sqlx::query(r#"DELETE FROM login_attempts WHERE key = ?1"#)
+        .bind(key)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::temp_pool;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_failures_are_all_counted() {
+        let (_dir, pool) = temp_pool().await;
+        let policy = LoginThrottle {
+            free_attempts: 100,
+            lockout_threshold: 100,
+            ..Default::default()
+        };
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for _ in 0..5 {
+            let (pool, policy) = (pool.clone(), policy.clone());
+            tasks.spawn(async move { record_failure(&pool, "id:ghost", None, &policy, 1000).await });
+        }
+        while let Some(result) = tasks.join_next().await {
+            result.unwrap().unwrap();
+        }
+
+        assert_eq!(get(&pool, "id:ghost").await.unwrap().unwrap().failed_count, 5);
+    }
+}
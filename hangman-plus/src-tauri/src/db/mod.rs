@@ -1,13 +1,25 @@
 use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
 use std::fs;
 use std::path::PathBuf;
+
+use crate::config::SessionPolicy;
+
+pub mod audit_log;
 pub mod card_attempts;
+pub mod card_revisions;
+pub mod card_search;
+pub mod login_attempts;
+
+#[cfg(test)]
+pub mod test_util;
 
 
 // export pod-modula (db/cards.rs)
 pub mod cards;
 
-pub async fn init_db(db_path: PathBuf) -> Result<SqlitePool, String> {
+// `sessions` is the configured policy, used to give sessions from before
+// expiry existed the same lifetime new logins get.
+pub async fn init_db(db_path: PathBuf, sessions: &SessionPolicy) -> Result<SqlitePool, String> {
     // napravi parent folder da SQLite može da kreira fajl
     if let Some(parent) = db_path.parent() {
         fs::create_dir_all(parent)
@@ -81,6 +93,43 @@ pub async fn init_db(db_path: PathBuf) -> Result<SqlitePool, String> {
     .await
     .map_err(|e| format!("Create sessions failed: {e}"))?;
 
+    // session expiry (absolute + idle); older rows get the configured policy
+    let _ = sqlx::query(r#"ALTER TABLE sessions ADD COLUMN last_seen_at INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+    let _ = sqlx::query(r#"ALTER TABLE sessions ADD COLUMN expires_at INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+    let _ = sqlx::query(r#"ALTER TABLE sessions ADD COLUMN idle_timeout INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // device label shown in the session list (user-agent summary sent at login)
+    let _ = sqlx::query(r#"ALTER TABLE sessions ADD COLUMN label TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(
+        r#"CREATE INDEX IF NOT EXISTS idx_sessions_user ON sessions(user_id, revoked_at);"#,
+    )
+    .execute(&pool)
+    .await;
+
+    sqlx::query(
+        r#"
+        UPDATE sessions
+        SET expires_at = created_at + ?1,
+            idle_timeout = ?2,
+            last_seen_at = COALESCE(last_seen_at, created_at)
+        WHERE expires_at IS NULL
+        "#,
+    )
+    .bind(sessions.absolute_secs())
+    .bind(sessions.idle_secs())
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Backfill session expiry failed: {e}"))?;
+
 
     // CARDS  
  
@@ -99,5 +148,275 @@ pub async fn init_db(db_path: PathBuf) -> Result<SqlitePool, String> {
     .await
     .map_err(|e| format!("Create cards failed: {e}"))?;
 
+    // moderation columns (older databases were created without them)
+    let _ = sqlx::query(
+        r#"ALTER TABLE cards ADD COLUMN status TEXT NOT NULL DEFAULT 'APPROVED';"#,
+    )
+    .execute(&pool)
+    .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN created_by INTEGER NOT NULL DEFAULT 0;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN created_at INTEGER NOT NULL DEFAULT 0;"#)
+        .execute(&pool)
+        .await;
+
+    // hotspot region (JSON) on a shared diagram image
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN region TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // TRUSTED PUBLISHERS (ed25519 keys whose signed packs are trusted)
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS trusted_publishers (
+          id INTEGER PRIMARY KEY AUTOINCREMENT,
+          name TEXT NOT NULL,
+          public_key TEXT NOT NULL UNIQUE,
+          added_by INTEGER NOT NULL,
+          created_at INTEGER NOT NULL
+        );
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create trusted_publishers failed: {e}"))?;
+
+    // optional extras (filled by bulk import)
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN synonyms TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN description TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // CARD REVISIONS (history of every change, old/new values as JSON)
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS card_revisions (
+          id INTEGER PRIMARY KEY AUTOINCREMENT,
+          card_id INTEGER NOT NULL,
+          action TEXT NOT NULL,
+          editor_id INTEGER NOT NULL,
+          created_at INTEGER NOT NULL,
+          old_values TEXT NULL,
+          new_values TEXT NULL,
+          note TEXT NULL
+        );
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create card_revisions failed: {e}"))?;
+
+    let _ = sqlx::query(
+        r#"CREATE INDEX IF NOT EXISTS idx_card_revisions_card ON card_revisions(card_id, id);"#,
+    )
+    .execute(&pool)
+    .await;
+
+    // soft delete: archived cards stay in the table (and in stats) but are not played
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN archived_at INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // reviewer feedback for the submitter
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN review_comment TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN reviewed_by INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN reviewed_at INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // 0 = the submitter hasn't seen the approve/reject decision yet
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN decision_seen INTEGER NOT NULL DEFAULT 1;"#)
+        .execute(&pool)
+        .await;
+
+    // duplicate detection: content hash of the image file (filled lazily)
+    // and the likely duplicates found when the card was submitted (JSON)
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN image_hash TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN possible_duplicates TEXT NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // 0..1 score from the terms and win history, refreshed by DifficultyService
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN difficulty REAL NULL;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN difficulty_updated_at INTEGER NULL;"#)
+        .execute(&pool)
+        .await;
+
+    // Elo rating of the card as an "opponent" (see RatingService)
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN rating REAL NOT NULL DEFAULT 1500;"#)
+        .execute(&pool)
+        .await;
+
+    let _ = sqlx::query(r#"ALTER TABLE cards ADD COLUMN rating_games INTEGER NOT NULL DEFAULT 0;"#)
+        .execute(&pool)
+        .await;
+
+    // USER RATINGS (one Elo rating per player and category)
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS user_ratings (
+          user_id INTEGER NOT NULL,
+          category TEXT NOT NULL,
+          rating REAL NOT NULL,
+          games INTEGER NOT NULL,
+          updated_at INTEGER NOT NULL,
+          PRIMARY KEY (user_id, category)
+        );
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create user_ratings failed: {e}"))?;
+
+    // BOT MATCHES (head-to-head rounds against the computer opponent)
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS bot_matches (
+          id INTEGER PRIMARY KEY AUTOINCREMENT,
+          user_id INTEGER NOT NULL,
+          game_id TEXT NULL,
+          card_id INTEGER NOT NULL,
+          language TEXT NOT NULL,
+          level TEXT NOT NULL,
+          max_wrong INTEGER NOT NULL,
+          player_wrong INTEGER NOT NULL,
+          player_solved INTEGER NOT NULL,
+          bot_wrong INTEGER NOT NULL,
+          bot_solved INTEGER NOT NULL,
+          bot_guesses TEXT NOT NULL,
+          outcome TEXT NOT NULL,
+          played_at INTEGER NOT NULL
+        );
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create bot_matches failed: {e}"))?;
+
+    let _ = sqlx::query(
+        r#"CREATE INDEX IF NOT EXISTS idx_bot_matches_user ON bot_matches(user_id, played_at);"#,
+    )
+    .execute(&pool)
+    .await;
+
+    // AUDIT LOG (append-only record of privileged actions)
+
+    sqlx::query(
+        r#"
+        CREATE TABLE IF NOT EXISTS audit_log (
+          id INTEGER PRIMARY KEY AUTOINCREMENT,
+          actor_id INTEGER NOT NULL,
+          action TEXT NOT NULL,
+          target_type TEXT NOT NULL,
+          target_id INTEGER NULL,
+          before_values TEXT NULL,
+          after_values TEXT NULL,
+          created_at INTEGER NOT NULL
+        );
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create audit_log failed: {e}"))?;
+
+    sqlx::query(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS audit_log_no_update
+        BEFORE UPDATE ON audit_log
+        BEGIN
+          SELECT RAISE(ABORT, 'audit_log is append-only');
+        END;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create audit_log trigger failed: {e}"))?;
+
+    sqlx::query(
+        r#"
+        CREATE TRIGGER IF NOT EXISTS audit_log_no_delete
+        BEFORE DELETE ON audit_log
+        BEGIN
+          SELECT RAISE(ABORT, 'audit_log is append-only');
+        END;
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Create audit_log trigger failed: {e}"))?;
+
+    let _ = sqlx::query(
+        r#"CREATE INDEX IF NOT EXISTS idx_audit_log_time ON audit_log(created_at, id);"#,
+    )
+    .execute(&pool)
+    .await;
+
+    // attempts are purged together with their card, so the table must exist up front
+    card_attempts::init(&pool).await?;
+    login_attempts::init(&pool).await?;
+
+    // needs the cards table above for its triggers and first build
+    card_search::init(&pool).await?;
+
     Ok(pool)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn old_sessions_get_the_configured_policy() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("test.db");
+        fs::write(&db_path, b"").unwrap();
+
+        let pool = init_db(db_path.clone(), &SessionPolicy::default()).await.unwrap();
+        sqlx::query(
+            r#"
+            INSERT INTO users (first_name, last_name, username, email, password_hash, role, created_at)
+            VALUES ('A', 'B', 'ab', 'ab@example.com', 'x', 'USER', 0);
+            INSERT INTO sessions (user_id, token_hash, created_at) VALUES (1, 'old', 1000);
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        pool.close().await;
+
+        let policy = SessionPolicy {
+            absolute_hours: 1,
+            idle_minutes: 10,
+        };
+        let pool = init_db(db_path, &policy).await.unwrap();
+
+        let (expires_at, idle_timeout): (i64, i64) =
+            sqlx::query_as("SELECT expires_at, idle_timeout FROM sessions WHERE token_hash = 'old'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((expires_at, idle_timeout), (1000 + 3600, 600));
+    }
+}
@@ -0,0 +1,43 @@
+// Throwaway database and logged-in users for service tests
+use sqlx::SqlitePool;
+use tempfile::TempDir;
+
+use crate::config::{AppConfig, SessionPolicy};
+use crate::services::auth_service::AuthService;
+
+// Fresh database in a temp folder; keep the TempDir alive for the test
+pub async fn temp_pool() -> (TempDir, SqlitePool) {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("test.db");
+
+    // init_db connects without create, like the app does on an existing file
+    std::fs::write(&db_path, b"").unwrap();
+    let pool = super::init_db(db_path, &SessionPolicy::default()).await.unwrap();
+    (dir, pool)
+}
+
+// Registers `username` (password "password1") with `role` and returns a session token
+pub async fn login_as(pool: &SqlitePool, username: &str, role: &str) -> String {
+    AuthService::register_user(
+        pool,
+        "Test".into(),
+        "User".into(),
+        username.into(),
+        format!("{username}@example.com"),
+        "password1".into(),
+    )
+    .await
+    .unwrap();
+
+    sqlx::query("UPDATE users SET role = ?1 WHERE username = ?2")
+        .bind(role)
+        .bind(username)
+        .execute(pool)
+        .await
+        .unwrap();
+
+    AuthService::login_user(pool, username.into(), "password1".into(), None, &AppConfig::default())
+        .await
+        .unwrap()
+        .session_token
+}
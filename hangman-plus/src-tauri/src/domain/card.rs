@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::category::Category;
+use crate::domain::{category::Category, region::Region};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Card {
@@ -9,4 +9,43 @@ pub struct Card {
     pub english: String,
     pub latin: String,
     pub image_path: String,
+    pub region: Option<Region>, // highlighted structure when image_path is a shared diagram
+    pub difficulty: Option<f64>, // None until the first difficulty refresh
+}
+
+// Review state of a card (archiving is separate, see cards.archived_at)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+impl CardStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardStatus::Pending => "PENDING",
+            CardStatus::Approved => "APPROVED",
+            CardStatus::Rejected => "REJECTED",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<CardStatus> {
+        match s.trim().to_uppercase().as_str() {
+            "PENDING" => Some(CardStatus::Pending),
+            "APPROVED" => Some(CardStatus::Approved),
+            "REJECTED" => Some(CardStatus::Rejected),
+            _ => None,
+        }
+    }
+
+    // Optional list filter; blank means every status
+    pub fn parse_filter(raw: Option<&str>) -> Result<Option<CardStatus>, String> {
+        match raw.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(st) => CardStatus::parse(st)
+                .map(Some)
+                .ok_or_else(|| "Status must be PENDING, APPROVED or REJECTED.".to_string()),
+            None => Ok(None),
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Category {
     Bones,
@@ -8,10 +8,30 @@ pub enum Category {
 }
 
 impl Category {
+    pub const ALL: [Category; 2] = [Category::Bones, Category::Organs];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Category::Bones => "BONES",
             Category::Organs => "ORGANS",
         }
     }
+
+    pub fn parse(s: &str) -> Option<Category> {
+        match s.trim().to_uppercase().as_str() {
+            "BONES" => Some(Category::Bones),
+            "ORGANS" => Some(Category::Organs),
+            _ => None,
+        }
+    }
+
+    // Optional list filter; blank means every category
+    pub fn parse_filter(raw: Option<&str>) -> Result<Option<Category>, String> {
+        match raw.map(str::trim).filter(|s| !s.is_empty()) {
+            Some(c) => Category::parse(c)
+                .map(Some)
+                .ok_or_else(|| "Category must be ORGANS or BONES.".to_string()),
+            None => Ok(None),
+        }
+    }
 }
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use sqlx::types::Json;
 use sqlx::FromRow;
 
+use crate::domain::region::Region;
+
 #[derive(Serialize)]
 pub struct LoginResponse {
     pub session_token: String,
@@ -11,6 +14,7 @@ pub struct MeResponse {
     pub id: i64,
     pub username: String,
     pub role: String,
+    pub permissions: Vec<String>, // "cards.review", ...
 }
 
 #[derive(Deserialize)]
@@ -20,12 +24,25 @@ pub struct CreateCardInput {
     pub english: String,
     pub latin: String,
     pub imagePath: String,
+    pub region: Option<Region>,
 }
 
 #[derive(Serialize)]
 pub struct CardResponse {
     pub id: i64,
     pub status: String,
+    pub duplicates: Vec<DuplicateMatch>, // likely duplicates, the card is saved anyway
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateMatch {
+    pub card_id: i64,
+    pub english: String,
+    pub latin: String,
+    pub status: String,
+    pub reason: String, // SAME_TERM | SIMILAR_TERM | SAME_IMAGE
+    pub score: f64,
 }
 
 #[derive(Serialize, FromRow)]
@@ -35,9 +52,28 @@ pub struct PendingCard {
     pub english: String,
     pub latin: String,
     pub image_path: String,
+    pub region: Option<Json<Region>>,
     pub status: String,
     pub created_by: i64,
     pub created_at: i64,
+    // last review before the submitter edited and resubmitted the card
+    pub review_comment: Option<String>,
+    pub reviewed_at: Option<i64>,
+    pub resubmit_count: i64,
+    pub possible_duplicates: Option<Json<Vec<DuplicateMatch>>>,
+}
+
+// Submitter edits their own PENDING/REJECTED card and sends it back for review
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResubmitCardInput {
+    pub session_token: String,
+    pub card_id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub region: Option<Region>,
 }
 
 #[derive(Deserialize)]
@@ -47,6 +83,7 @@ pub struct UpdateCardInput {
     pub english: String,
     pub latin: String,
     pub imagePath: String,
+    pub region: Option<Region>,
 }
 
 #[derive(Serialize, FromRow)]
@@ -56,7 +93,385 @@ pub struct CardAdminItem {
     pub english: String,
     pub latin: String,
     pub image_path: String,
+    pub region: Option<Json<Region>>,
+    pub status: String,
+    pub created_by: i64,
+    pub created_at: i64,
+}
+
+// Card fields as they were before/after a change (stored as JSON in card_revisions)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSnapshot {
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub region: Option<Json<Region>>,
+    pub synonyms: Option<String>,
+    pub description: Option<String>,
+    pub status: String,
+}
+
+#[derive(Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CardRevision {
+    pub id: i64,
+    pub card_id: i64,
+    pub action: String, // CREATE | UPDATE | APPROVE | REJECT | RESUBMIT | REVERT | ARCHIVE | RESTORE | PURGE
+    pub editor_id: i64,
+    pub editor_username: Option<String>,
+    pub created_at: i64,
+    pub old_values: Option<Json<CardSnapshot>>,
+    pub new_values: Option<Json<CardSnapshot>>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchReviewResult {
+    pub card_id: i64,
+    pub ok: bool,
+    pub status: Option<String>, // new status when ok
+    pub error: Option<String>,
+}
+
+impl BatchReviewResult {
+    pub fn failed(card_id: i64, error: impl Into<String>) -> Self {
+        Self {
+            card_id,
+            ok: false,
+            status: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+#[derive(Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct MyCardRequest {
+    pub id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub status: String,
+    pub created_at: i64,
+    pub review_comment: Option<String>,
+    pub reviewed_at: Option<i64>,
+    pub decision_seen: bool,
+}
+
+// One page of a longer list
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+}
+
+#[derive(Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub actor_id: i64,
+    pub actor_username: Option<String>,
+    pub action: String,
+    pub target_type: String, // CARD | CARDS | PACK | PUBLISHER | USER
+    pub target_id: Option<i64>,
+    pub before_values: Option<Json<serde_json::Value>>,
+    pub after_values: Option<Json<serde_json::Value>>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAdminItem {
+    pub id: i64,
+    pub username: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub email: String,
+    pub role: String, // ADMIN | MODERATOR | USER
+    pub created_at: i64,
+    pub locked_until: Option<i64>, // set while too many failed logins keep the account locked
+}
+
+// All filters are optional; page starts at 1
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<i64>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<i64>,
+    pub from: Option<i64>, // unix seconds, inclusive
+    pub to: Option<i64>,   // unix seconds, exclusive
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+// Filters for the admin card lists; everything optional, page starts at 1
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CardListQuery {
+    pub status: Option<String>, // ignored by the pending list
+    pub category: Option<String>,
+    pub created_by: Option<i64>,
+    pub from: Option<i64>, // unix seconds, inclusive
+    pub to: Option<i64>,   // unix seconds, exclusive
+    pub search: Option<String>, // english, latin or synonyms
+    pub sort: Option<String>, // NEWEST (default) | OLDEST | ENGLISH | LATIN | CATEGORY
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+#[derive(Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedCard {
+    pub id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub status: String,
+    pub archived_at: i64,
+    pub attempt_count: i64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeCardResult {
+    pub card_id: i64,
+    pub attempts: String, // "DELETE" | "ANONYMIZE"
+    pub affected_attempts: u64,
+}
+
+#[derive(Serialize)]
+pub struct MissingImageCard {
+    pub id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub status: String,
+    pub problem: String, // IMAGE_NOT_FOUND | IMAGE_PATH_TRAVERSAL | ...
+}
+
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct UserRating {
+    pub category: String,
+    pub rating: f64,
+    pub games: i64,
+}
+
+// A signed-in device of the current user; only live sessions are listed
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionItem {
+    pub id: i64,
+    pub label: Option<String>,
+    pub created_at: i64,
+    pub last_seen_at: i64,
+    pub expires_at: i64,
+    #[sqlx(skip)]
+    pub current: bool,
+}
+
+// One finished card the player wants to race the bot on
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotRoundInput {
+    pub session_token: String,
+    pub game_id: Option<String>, // the run the round belongs to
+    pub card_id: i64,
+    pub language: String, // "EN" | "LAT"
+    pub level: String,    // "EASY" | "MEDIUM" | "HARD"
+    pub max_wrong: i64,
+    pub player_wrong: i64,
+    pub player_solved: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BotRoundResult {
+    pub id: i64,
+    pub bot_guesses: Vec<String>, // in play order, for replaying the race
+    pub bot_wrong: i64,
+    pub bot_solved: bool,
+    pub outcome: String, // PLAYER | BOT | DRAW
+}
+
+// Head-to-head totals against one bot level
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct BotRecord {
+    pub level: String,
+    pub played: i64,
+    pub wins: i64,
+    pub losses: i64,
+    pub draws: i64,
+}
+
+// Full-text search result, best match first
+#[derive(Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CardSearchHit {
+    pub id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub status: String,
+    pub score: f64, // higher is more relevant
+}
+
+// Filters for the per-card analytics list; page starts at 1
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CardAnalyticsQuery {
+    pub category: Option<String>,
+    pub flagged_only: bool,
+    pub sort: Option<String>, // HARDEST (default) | EASIEST | MOST_PLAYED
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+// One card aggregated over every player's attempts
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct CardAnalyticsRow {
+    pub card_id: i64,
+    pub category: String,
+    pub english: String,
+    pub latin: String,
     pub status: String,
+    pub attempts: i64,
+    pub wins: i64,
+    pub win_rate: Option<f64>, // None until the card has been played
+    pub avg_wrong: Option<f64>,
+    pub flag: Option<String>, // TOO_HARD | TOO_EASY
+    pub rating: f64,          // Elo rating of the card as an opponent
+    #[sqlx(skip)]
+    pub top_wrong_letters: Vec<LetterCount>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LetterCount {
+    pub letter: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct ModeBreakdown {
+    pub language: Option<String>,
+    pub difficulty: Option<String>,
+    pub attempts: i64,
+    pub win_rate: f64,
+    pub avg_wrong: Option<f64>,
+}
+
+// Drill-down for one card
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardAnalyticsDetail {
+    pub summary: CardAnalyticsRow,
+    pub wrong_letters: Vec<LetterCount>, // every letter, most frequent first
+    pub by_mode: Vec<ModeBreakdown>,
+    pub bands: crate::config::DifficultyBands,
+}
+
+// ---------- BULK IMPORT DTOs ----------
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowResult {
+    pub row: usize, // 1-based, header not counted
+    pub english: String,
+    pub status: String, // "OK" | "ERROR" | "DUPLICATE"
+    pub errors: Vec<String>,
+    pub duplicate_of: Option<i64>, // existing card id, if the duplicate is in the DB
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub error_rows: usize,
+    pub duplicate_rows: usize,
+    pub imported: usize,
+    pub rows: Vec<ImportRowResult>,
+}
+
+// ---------- CONTENT PACK DTOs ----------
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackExportResponse {
+    pub file_path: String,
+    pub card_count: usize,
+    pub image_count: usize,
+    pub signed: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackImportRow {
+    pub english: String,
+    pub latin: String,
+    pub status: String, // "IMPORTED" | "DUPLICATE_TERM" | "DUPLICATE_IMAGE" | "ERROR"
+    pub message: Option<String>,
+    pub card_id: Option<i64>, // new card, or the existing one it duplicates
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackImportReport {
+    pub name: String,
+    pub format_version: u32,
+    pub languages: Vec<String>,
+    pub signature: String,         // "VERIFIED" | "UNTRUSTED" | "UNSIGNED"
+    pub publisher: Option<String>, // trusted publisher name when verified
+    pub card_status: String,       // status given to imported cards
+    pub imported: usize,
+    pub skipped: usize,
+    pub rows: Vec<PackImportRow>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackInspectResponse {
+    pub name: String,
+    pub format_version: u32,
+    pub created_at: i64,
+    pub languages: Vec<String>,
+    pub card_count: usize,
+    pub signature: String,
+    pub publisher: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedPublisher {
+    pub id: i64,
+    pub name: String,
+    pub public_key: String,
+    pub added_by: i64,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SigningKeyResponse {
+    pub secret_key: String, // keep it private, needed to sign exports
+    pub public_key: String, // share it, other faculties add it as trusted
 }
 
 // ---------- ANALYTICS DTOs ----------
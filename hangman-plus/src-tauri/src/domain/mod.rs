@@ -1,4 +1,8 @@
 pub mod dto;
 pub mod category;
 pub mod card;
+pub mod pack;
+pub mod region;
 
+pub mod validation;
+pub mod role;
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::region::Region;
+
+// .hpack = zip archive with
+//   manifest.json   -> PackManifest
+//   manifest.sig    -> PackSignature (optional, ed25519 over the manifest bytes)
+//   cards.json      -> Vec<PackCard>
+//   images/<sha256>.<ext>
+pub const PACK_FORMAT_VERSION: u32 = 1;
+pub const MANIFEST_FILE: &str = "manifest.json";
+pub const SIGNATURE_FILE: &str = "manifest.sig";
+pub const CARDS_FILE: &str = "cards.json";
+pub const IMAGES_DIR: &str = "images/";
+
+// Raster formats only: a pack must not be able to smuggle in svg (scripts) or other files
+pub const PACK_IMAGE_EXTS: [&str; 5] = ["png", "jpg", "jpeg", "webp", "gif"];
+
+// Every card carries both terms, so every pack has both languages
+pub const PACK_LANGUAGES: [&str; 2] = ["EN", "LAT"];
+
+// Uncompressed size limits, checked while reading (zip bombs)
+pub const MAX_ENTRY_BYTES: u64 = 16 * 1024 * 1024;
+pub const MAX_PACK_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackManifest {
+    pub format_version: u32,
+    pub name: String,
+    pub created_at: i64,
+    pub languages: Vec<String>, // "EN" | "LAT"
+    pub card_count: usize,
+    pub files: Vec<PackFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackFile {
+    pub path: String,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackCard {
+    pub category: String,
+    pub english: String,
+    pub latin: String,
+    pub image: String, // "images/<sha256>.<ext>" inside the archive
+    #[serde(default)]
+    pub region: Option<Region>,
+    #[serde(default)]
+    pub synonyms: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PackSignature {
+    pub public_key: String, // hex
+    pub signature: String,  // hex
+}
+
+impl PackManifest {
+    pub fn file(&self, path: &str) -> Option<&PackFile> {
+        self.files.iter().find(|f| f.path == path)
+    }
+}
+
+// Only "images/<hex>.<ext>" names are accepted from an archive
+pub fn is_pack_image_name(name: &str) -> bool {
+    let Some(file) = name.strip_prefix(IMAGES_DIR) else {
+        return false;
+    };
+
+    match file.split_once('.') {
+        Some((hash, ext)) => {
+            hash.len() == 64
+                && hash.chars().all(|c| c.is_ascii_hexdigit())
+                && PACK_IMAGE_EXTS.contains(&ext)
+        }
+        None => false,
+    }
+}
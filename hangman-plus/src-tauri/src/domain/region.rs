@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+// Highlighted structure on a shared diagram image.
+// Coordinates are fractions of the image size (0..1), so the same region
+// works no matter how big the image is drawn in the UI.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Region {
+    Box {
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+    },
+    Polygon {
+        points: Vec<Point>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+fn in_unit(v: f64) -> bool {
+    (0.0..=1.0).contains(&v)
+}
+
+impl Region {
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Region::Box {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                if !(in_unit(*x) && in_unit(*y)) {
+                    return Err("Region must start inside the image.".into());
+                }
+                if *width <= 0.0 || *height <= 0.0 {
+                    return Err("Region must have a size.".into());
+                }
+                if x + width > 1.0 || y + height > 1.0 {
+                    return Err("Region must stay inside the image.".into());
+                }
+            }
+            Region::Polygon { points } => {
+                if points.len() < 3 {
+                    return Err("Polygon region needs at least 3 points.".into());
+                }
+                if !points.iter().all(|p| in_unit(p.x) && in_unit(p.y)) {
+                    return Err("Region must stay inside the image.".into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn region_json_shape() {
+        let r: Region = serde_json::from_str(
+            r#"{"kind":"BOX","x":0.1,"y":0.2,"width":0.3,"height":0.4}"#,
+        )
+        .unwrap();
+        assert!(r.validate().is_ok());
+
+        let p: Region = serde_json::from_str(
+            r#"{"kind":"POLYGON","points":[{"x":0,"y":0},{"x":1,"y":0},{"x":0.5,"y":1}]}"#,
+        )
+        .unwrap();
+        assert!(p.validate().is_ok());
+    }
+
+    #[test]
+    fn region_outside_image_is_rejected() {
+        let r = Region::Box {
+            x: 0.8,
+            y: 0.1,
+            width: 0.3,
+            height: 0.1,
+        };
+        assert!(r.validate().is_err());
+
+        let p = Region::Polygon {
+            points: vec![Point { x: 0.1, y: 0.1 }, Point { x: 0.2, y: 0.2 }],
+        };
+        assert!(p.validate().is_err());
+    }
+}
@@ -0,0 +1,101 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Role {
+    Admin,
+    Moderator,
+    User,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Permission {
+    CardsReview, // pending queue, approve/reject
+    CardsEdit,   // add approved cards, edit, history/revert
+    CardsDelete, // archive, restore, purge
+    CardsImport, // bulk import, content packs
+    PacksManage, // trusted publishers, signing keys
+    AuditView,
+    StatsView,   // per-card analytics across all players
+    UsersManage, // change roles
+}
+
+impl Permission {
+    pub const ALL: [Permission; 8] = [
+        Permission::CardsReview,
+        Permission::CardsEdit,
+        Permission::CardsDelete,
+        Permission::CardsImport,
+        Permission::PacksManage,
+        Permission::AuditView,
+        Permission::StatsView,
+        Permission::UsersManage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::CardsReview => "cards.review",
+            Permission::CardsEdit => "cards.edit",
+            Permission::CardsDelete => "cards.delete",
+            Permission::CardsImport => "cards.import",
+            Permission::PacksManage => "packs.manage",
+            Permission::AuditView => "audit.view",
+            Permission::StatsView => "stats.view",
+            Permission::UsersManage => "users.manage",
+        }
+    }
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Admin => "ADMIN",
+            Role::Moderator => "MODERATOR",
+            Role::User => "USER",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Role> {
+        match s.trim().to_uppercase().as_str() {
+            "ADMIN" => Some(Role::Admin),
+            "MODERATOR" => Some(Role::Moderator),
+            "USER" => Some(Role::User),
+            _ => None,
+        }
+    }
+
+    pub fn permissions(&self) -> &'static [Permission] {
+        match self {
+            Role::Admin => &Permission::ALL,
+            Role::Moderator => &[Permission::CardsReview],
+            Role::User => &[],
+        }
+    }
+
+    pub fn can(&self, permission: Permission) -> bool {
+        self.permissions().contains(&permission)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moderator_reviews_but_cannot_delete_or_manage_users() {
+        assert!(Role::Moderator.can(Permission::CardsReview));
+        assert!(!Role::Moderator.can(Permission::CardsEdit));
+        assert!(!Role::Moderator.can(Permission::CardsDelete));
+        assert!(!Role::Moderator.can(Permission::UsersManage));
+        assert!(Role::Admin.can(Permission::UsersManage));
+        assert!(!Role::User.can(Permission::CardsReview));
+    }
+
+    #[test]
+    fn role_names_round_trip() {
+        for role in [Role::Admin, Role::Moderator, Role::User] {
+            assert_eq!(Role::parse(role.as_str()), Some(role));
+        }
+        assert_eq!(Role::parse("root"), None);
+    }
+}
@@ -0,0 +1,161 @@
+use serde::Serialize;
+
+use crate::domain::category::Category;
+use crate::domain::region::Region;
+
+pub const MAX_TERM_LEN: usize = 60;
+const MIN_TERM_LETTERS: usize = 2;
+
+// One problem with one input; `field` uses the UI names (english, imagePath, ...)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub code: &'static str,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new(field: &'static str, code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+// Raw card input from any write path
+pub struct CardFields<'a> {
+    pub category: Option<&'a str>,   // None = this path doesn't change the category
+    pub english: &'a str,
+    pub latin: &'a str,
+    pub image_path: Option<&'a str>, // None = image comes from elsewhere (packs)
+    pub region: Option<&'a Region>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ValidCardFields {
+    pub category: Option<Category>,
+    pub english: String,
+    pub latin: String,
+}
+
+// Terms are played letter by letter, so only letters (accents are fine),
+// spaces, hyphens and apostrophes make sense.
+fn is_term_char(c: char) -> bool {
+    c.is_alphabetic() || c == ' ' || c == '-' || c == '\''
+}
+
+fn check_term(field: &'static str, label: &str, raw: &str, errors: &mut Vec<FieldError>) -> String {
+    let term = raw.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if term.is_empty() {
+        errors.push(FieldError::new(field, "REQUIRED", format!("{label} term is required.")));
+    } else if term.chars().count() > MAX_TERM_LEN {
+        errors.push(FieldError::new(
+            field,
+            "TOO_LONG",
+            format!("{label} term can be at most {MAX_TERM_LEN} characters."),
+        ));
+    } else if !term.chars().all(is_term_char) {
+        errors.push(FieldError::new(
+            field,
+            "INVALID_CHARACTERS",
+            format!("{label} term may only contain letters, spaces, hyphens and apostrophes."),
+        ));
+    } else if term.chars().filter(|c| c.is_alphabetic()).count() < MIN_TERM_LETTERS {
+        errors.push(FieldError::new(
+            field,
+            "TOO_SHORT",
+            format!("{label} term needs at least {MIN_TERM_LETTERS} letters."),
+        ));
+    }
+
+    term
+}
+
+pub fn validate_card(fields: &CardFields) -> Result<ValidCardFields, Vec<FieldError>> {
+    let mut errors = Vec::new();
+
+    let category = match fields.category {
+        Some(raw) => {
+            let parsed = Category::parse(raw);
+            if parsed.is_none() {
+                errors.push(FieldError::new(
+                    "category",
+                    "CATEGORY_INVALID",
+                    format!("Unknown category: {}", raw.trim()),
+                ));
+            }
+            parsed
+        }
+        None => None,
+    };
+
+    let english = check_term("english", "English", fields.english, &mut errors);
+    let latin = check_term("latin", "Latin", fields.latin, &mut errors);
+
+    if let Some(path) = fields.image_path {
+        if path.trim().is_empty() {
+            errors.push(FieldError::new("imagePath", "REQUIRED", "Image path is required."));
+        }
+    }
+
+    if let Some(region) = fields.region {
+        if let Err(msg) = region.validate() {
+            errors.push(FieldError::new("region", "REGION_INVALID", msg));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(ValidCardFields {
+            category,
+            english,
+            latin,
+        })
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields<'a>(category: &'a str, english: &'a str, latin: &'a str) -> CardFields<'a> {
+        CardFields {
+            category: Some(category),
+            english,
+            latin,
+            image_path: Some("/cards/bones/femur.png"),
+            region: None,
+        }
+    }
+
+    #[test]
+    fn cleans_valid_input() {
+        let ok = validate_card(&fields("bones", "  Thigh   bone ", "Os femoris")).unwrap();
+        assert_eq!(ok.category, Some(Category::Bones));
+        assert_eq!(ok.english, "Thigh bone");
+        assert_eq!(ok.latin, "Os femoris");
+    }
+
+    #[test]
+    fn reports_every_bad_field() {
+        let errors = validate_card(&fields("SKIN", "", "Femur<script>")).unwrap_err();
+        let got: Vec<_> = errors.iter().map(|e| (e.field, e.code)).collect();
+        assert_eq!(
+            got,
+            vec![
+                ("category", "CATEGORY_INVALID"),
+                ("english", "REQUIRED"),
+                ("latin", "INVALID_CHARACTERS"),
+            ]
+        );
+
+        let long = "a".repeat(MAX_TERM_LEN + 1);
+        let errors = validate_card(&fields("ORGANS", &long, "X")).unwrap_err();
+        assert_eq!(errors[0].code, "TOO_LONG");
+        assert_eq!(errors[1].code, "TOO_SHORT");
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::domain::validation::FieldError;
+
+// Error returned to the UI.
+// Serializes as { code, message } so the frontend can keep showing `e.message`
+// and branch on `e.code` where it needs to. Validation errors also carry
+// `fieldErrors` so each input can show its own message.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppError {
+    pub code: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub field_errors: Vec<FieldError>,
+}
+
+impl AppError {
+    pub fn new(code: &'static str, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            field_errors: Vec::new(),
+        }
+    }
+
+    pub fn validation(field_errors: Vec<FieldError>) -> Self {
+        let message = field_errors
+            .first()
+            .map(|e| e.message.clone())
+            .unwrap_or_else(|| "Please check the form.".into());
+
+        Self {
+            code: "VALIDATION_FAILED",
+            message,
+            field_errors,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+// Plain string errors (DB, auth, ...) keep working with `?`
+impl From<String> for AppError {
+    fn from(message: String) -> Self {
+        Self::new("ERROR", message)
+    }
+}
+
+impl From<&str> for AppError {
+    fn from(message: &str) -> Self {
+        Self::new("ERROR", message)
+    }
+}
+
+impl From<AppError> for String {
+    fn from(e: AppError) -> Self {
+        e.message
+    }
+}
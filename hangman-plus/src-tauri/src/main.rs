@@ -1,11 +1,19 @@
+mod config;
 mod db;
+mod errors;
 mod utils;
 mod domain;
 mod services;
 mod commands;
 
+use std::time::Duration;
+
 use tauri::Manager;
 
+use services::difficulty_service::DifficultyService;
+use services::duplicate_service::DuplicateService;
+use utils::assets::AssetRoots;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
@@ -21,17 +29,75 @@ pub fn run() {
                 let db_path = data_dir.join("hangman.db");
                 println!("SQLite DB path: {}", db_path.display());
 
-                let pool = db::init_db(db_path).await?;
+                // config first: the schema setup backfills sessions with its policy
+                let config = config::AppConfig::load(&data_dir.join("config.json"))?;
+
+                let pool = db::init_db(db_path, &config.sessions).await?;
+                let refresh_pool = pool.clone();
+                let backfill_pool = pool.clone();
                 app_handle.manage(pool);
 
+                // card difficulty: once now, then on a timer
+                let every = Duration::from_secs(config.difficulty_refresh_minutes * 60);
+                tauri::async_runtime::spawn(async move {
+                    let mut ticker = tokio::time::interval(every);
+                    loop {
+                        ticker.tick().await;
+                        if let Err(e) = DifficultyService::refresh_all(&refresh_pool).await {
+                            eprintln!("Card difficulty refresh failed: {e}");
+                        }
+                    }
+                });
+
+                app_handle.manage(config);
+
                 app_handle.manage(commands::game_commands::GamesState::default());
 
+                // card images may live in the bundled resources (public/cards) or in app data
+                let resource_dir = app_handle
+                    .path()
+                    .resource_dir()
+                    .map_err(|e| format!("resource_dir failed: {e}"))?;
+
+                let assets = AssetRoots::new(vec![resource_dir])
+                    .with_writable_root(data_dir.join("assets"));
+
+                // image hashes for duplicate checks, for cards written before they were stored
+                let backfill_assets = assets.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = DuplicateService::backfill_image_hashes(&backfill_pool, &backfill_assets).await {
+                        eprintln!("Image hash backfill failed: {e}");
+                    }
+                });
+
+                app_handle.manage(assets);
+
                 Ok::<(), String>(())
             })
             .map_err(|e| tauri::Error::Setup(Box::<dyn std::error::Error>::from(e).into()))?;
 
             Ok(())
         })
+        // card images by stored path, from the bundled cards or from app data
+        // (imported packs), which the webview cannot load directly
+        .register_uri_scheme_protocol("cardimg", |ctx, request| {
+            let served = ctx
+                .app_handle()
+                .try_state::<AssetRoots>()
+                .ok_or(utils::assets::ImagePathError::NotFound)
+                .and_then(|assets| assets.serve(request.uri().path()));
+
+            match served {
+                Ok((bytes, mime)) => tauri::http::Response::builder()
+                    .header("Content-Type", mime)
+                    .body(bytes)
+                    .unwrap(),
+                Err(_) => tauri::http::Response::builder()
+                    .status(404)
+                    .body(Vec::new())
+                    .unwrap(),
+            }
+        })
         .invoke_handler(tauri::generate_handler![
             // auth
             commands::auth_commands::register_user,
@@ -39,28 +105,71 @@ pub fn run() {
             commands::auth_commands::validate_session,
             commands::auth_commands::logout,
             commands::auth_commands::get_me,
+            commands::auth_commands::list_sessions,
+            commands::auth_commands::revoke_session,
+            commands::auth_commands::revoke_all_other_sessions,
 
             // game
             commands::game_commands::start_game,
             commands::game_commands::next_card,
             commands::game_commands::reset_game,
             commands::game_commands::end_game,
+            commands::game_commands::suggest_letter,
+            commands::game_commands::play_bot_round,
+            commands::game_commands::get_bot_record,
 
             // cards
             commands::card_commands::admin_add_card,
             commands::card_commands::user_request_card,
+            commands::card_commands::resubmit_card_request,
             commands::card_commands::approve_card,
             commands::card_commands::reject_card,
+            commands::card_commands::approve_cards,
+            commands::card_commands::reject_cards,
             commands::card_commands::list_pending_cards,
             commands::card_commands::count_pending_cards,
+            commands::card_commands::list_my_card_requests,
+            commands::card_commands::count_unread_card_decisions,
+            commands::card_commands::mark_card_decisions_seen,
             commands::card_commands::list_all_cards_admin,
             commands::card_commands::admin_update_card,
             commands::card_commands::admin_delete_card,
+            commands::card_commands::list_cards_missing_images,
+            commands::card_commands::admin_import_cards,
+            commands::card_commands::list_card_history,
+            commands::card_commands::revert_card,
+            commands::card_commands::list_archived_cards,
+            commands::card_commands::restore_card,
+            commands::card_commands::purge_card,
+            commands::card_commands::search_cards,
+            commands::card_commands::refresh_card_difficulty,
+
+            // audit log
+            commands::audit_commands::list_audit_log,
+
+            // users and roles
+            commands::user_commands::list_users,
+            commands::user_commands::set_user_role,
+            commands::user_commands::unlock_account,
+
+            // content packs
+            commands::pack_commands::admin_export_pack,
+            commands::pack_commands::admin_inspect_pack,
+            commands::pack_commands::admin_import_pack,
+            commands::pack_commands::list_trusted_publishers,
+            commands::pack_commands::add_trusted_publisher,
+            commands::pack_commands::remove_trusted_publisher,
+            commands::pack_commands::generate_signing_key,
 
             // stats + analytics
             commands::stats_commands::log_card_attempt,
             commands::stats_commands::get_user_stats,
+            commands::stats_commands::get_user_stats_for,
             commands::stats_commands::get_user_analytics,
+            commands::stats_commands::get_user_analytics_for,
+            commands::stats_commands::list_card_analytics,
+            commands::stats_commands::get_card_analytics,
+            commands::stats_commands::get_my_ratings,
 
             // profile
             commands::profile_commands::get_profile,
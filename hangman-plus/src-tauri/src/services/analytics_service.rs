@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+
+use crate::config::DifficultyBands;
+use crate::domain::category::Category;
+use crate::domain::dto::{
+    CardAnalyticsDetail, CardAnalyticsQuery, CardAnalyticsRow, LetterCount, ModeBreakdown, Page,
+};
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+use crate::utils::paging::page_bounds;
+
+const TOP_LETTERS: usize = 5;
+
+// Per-card aggregates with the band flag, as a CTE named `card_stats`.
+// Archived cards are left out, and attempts of purged cards (card_id NULL) never join.
+fn push_stats_cte<'a>(
+    qb: &mut QueryBuilder<'a, Sqlite>,
+    category: Option<Category>,
+    card_id: Option<i64>,
+    bands: &DifficultyBands,
+) {
+    qb.push(
+        r#"
+        WITH totals AS (
+            SELECT
+                c.id AS card_id,
+                c.category,
+                c.english,
+                c.latin,
+                c.status,
+                c.rating,
+                COUNT(a.id) AS attempts,
+                COALESCE(SUM(a.is_won), 0) AS wins,
+                AVG(a.wrong_count) AS avg_wrong
+            FROM cards c
+            LEFT JOIN card_attempts a ON a.card_id = c.id
+            WHERE c.archived_at IS NULL
+        "#,
+    );
+
+    if let Some(category) = category {
+        qb.push(" AND c.category = ").push_bind(category.as_str());
+    }
+    if let Some(card_id) = card_id {
+        qb.push(" AND c.id = ").push_bind(card_id);
+    }
+
+    qb.push(
+        r#"
+            GROUP BY c.id
+        ),
+        card_stats AS (
+            SELECT
+                *,
+                CASE WHEN attempts > 0 THEN CAST(wins AS REAL) / attempts END AS win_rate
+            FROM totals
+        )
+        SELECT
+            card_id, category, english, latin, status, rating, attempts, wins, win_rate, avg_wrong,
+            CASE
+                WHEN attempts < "#,
+    );
+    qb.push_bind(bands.min_attempts)
+        .push(" THEN NULL WHEN win_rate < ")
+        .push_bind(bands.min_win_rate)
+        .push(" THEN 'TOO_HARD' WHEN win_rate > ")
+        .push_bind(bands.max_win_rate)
+        .push(" THEN 'TOO_EASY' END AS flag FROM card_stats");
+}
+
+fn sort_clause(sort: Option<&str>) -> Result<&'static str, AppError> {
+    match sort.map(|s| s.trim().to_uppercase()).as_deref() {
+        None | Some("") | Some("HARDEST") => {
+            Ok(" ORDER BY win_rate IS NULL, win_rate ASC, attempts DESC, card_id ASC")
+        }
+        Some("EASIEST") => Ok(" ORDER BY win_rate IS NULL, win_rate DESC, attempts DESC, card_id ASC"),
+        Some("MOST_PLAYED") => Ok(" ORDER BY attempts DESC, card_id ASC"),
+        Some(other) => Err(format!("Unknown sort order: {other}.").into()),
+    }
+}
+
+// Each attempt stores its wrong letters once, so this counts attempts per letter
+fn count_letters<'a>(rows: impl Iterator<Item = &'a str>) -> Vec<LetterCount> {
+    let mut counts: HashMap<char, i64> = HashMap::new();
+    for letters in rows {
+        for c in letters.chars() {
+            *counts.entry(c).or_default() += 1;
+        }
+    }
+
+    let mut out: Vec<LetterCount> = counts
+        .into_iter()
+        .map(|(c, count)| LetterCount {
+            letter: c.to_string(),
+            count,
+        })
+        .collect();
+    out.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.letter.cmp(&b.letter)));
+    out
+}
+
+// (card_id, wrong_letters) for the given cards
+async fn wrong_letters_for(pool: &SqlitePool, card_ids: &[i64]) -> Result<Vec<(i64, String)>, AppError> {
+    if card_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut qb = QueryBuilder::<Sqlite>::new(
+        "SELECT card_id, wrong_letters FROM card_attempts WHERE wrong_letters IS NOT NULL AND card_id IN (",
+    );
+    let mut ids = qb.separated(", ");
+    for id in card_ids {
+        ids.push_bind(*id);
+    }
+    qb.push(")");
+
+    qb.build_query_as::<(i64, String)>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}").into())
+}
+
+pub struct AnalyticsService;
+
+impl AnalyticsService {
+    pub async fn list_card_analytics(
+        pool: &SqlitePool,
+        bands: &DifficultyBands,
+        session_token: String,
+        query: CardAnalyticsQuery,
+    ) -> Result<Page<CardAnalyticsRow>, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::StatsView).await?;
+
+        let category = Category::parse_filter(query.category.as_deref())?;
+        let order_by = sort_clause(query.sort.as_deref())?;
+        let (page, page_size) = page_bounds(query.page, query.page_size);
+
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM (");
+        push_stats_cte(&mut count, category, None, bands);
+        count.push(")");
+        if query.flagged_only {
+            count.push(" WHERE flag IS NOT NULL");
+        }
+
+        let total = count
+            .build_query_scalar::<i64>()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?;
+
+        let mut qb = QueryBuilder::<Sqlite>::new("SELECT * FROM (");
+        push_stats_cte(&mut qb, category, None, bands);
+        qb.push(")");
+        if query.flagged_only {
+            qb.push(" WHERE flag IS NOT NULL");
+        }
+        qb.push(order_by)
+            .push(" LIMIT ")
+            .push_bind(page_size)
+            .push(" OFFSET ")
+            .push_bind((page - 1) * page_size);
+
+        let mut items = qb
+            .build_query_as::<CardAnalyticsRow>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?;
+
+        let ids: Vec<i64> = items.iter().map(|r| r.card_id).collect();
+        let letters = wrong_letters_for(pool, &ids).await?;
+
+        for item in &mut items {
+            let mut top = count_letters(
+                letters
+                    .iter()
+                    .filter(|(id, _)| *id == item.card_id)
+                    .map(|(_, l)| l.as_str()),
+            );
+            top.truncate(TOP_LETTERS);
+            item.top_wrong_letters = top;
+        }
+
+        Ok(Page {
+            items,
+            total,
+            page,
+            page_size,
+        })
+    }
+
+    pub async fn get_card_analytics(
+        pool: &SqlitePool,
+        bands: &DifficultyBands,
+        session_token: String,
+        card_id: i64,
+    ) -> Result<CardAnalyticsDetail, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::StatsView).await?;
+
+        let mut qb = QueryBuilder::<Sqlite>::new("");
+        push_stats_cte(&mut qb, None, Some(card_id), bands);
+
+        let mut summary = qb
+            .build_query_as::<CardAnalyticsRow>()
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?
+            .ok_or_else(|| AppError::new("CARD_NOT_FOUND", "Card not found."))?;
+
+        let letters = wrong_letters_for(pool, &[card_id]).await?;
+        let wrong_letters = count_letters(letters.iter().map(|(_, l)| l.as_str()));
+        summary.top_wrong_letters = wrong_letters.iter().take(TOP_LETTERS).cloned().collect();
+
+        let by_mode = sqlx::query_as::<_, ModeBreakdown>(
+            r#"
+            SELECT
+                language,
+                difficulty,
+                COUNT(*) AS attempts,
+                CAST(SUM(is_won) AS REAL) / COUNT(*) AS win_rate,
+                AVG(wrong_count) AS avg_wrong
+            FROM card_attempts
+            WHERE card_id = ?1
+            GROUP BY language, difficulty
+            ORDER BY language, difficulty
+            "#,
+        )
+        .bind(card_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        Ok(CardAnalyticsDetail {
+            summary,
+            wrong_letters,
+            by_mode,
+            bands: bands.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::{login_as, temp_pool};
+
+    async fn card(pool: &SqlitePool, english: &str) -> i64 {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at)
+            VALUES ('BONES', ?1, ?1, '/cards/bones/test.png', 'APPROVED', 0)
+            RETURNING id
+            "#,
+        )
+        .bind(english)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    async fn attempt(pool: &SqlitePool, card_id: i64, won: bool, wrong_letters: &str) {
+        sqlx::query(
+            r#"
+            INSERT INTO card_attempts (user_id, card_id, is_won, language, difficulty, wrong_count, max_wrong, wrong_letters)
+            VALUES (1, ?1, ?2, 'EN', 'EASY', ?3, 6, ?4)
+            "#,
+        )
+        .bind(card_id)
+        .bind(won)
+        .bind(wrong_letters.len() as i64)
+        .bind(wrong_letters)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn flags_cards_outside_the_bands_and_counts_wrong_letters() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        let bands = DifficultyBands {
+            min_attempts: 3,
+            ..Default::default()
+        };
+
+        let hard = card(&pool, "Femur").await;
+        let easy = card(&pool, "Tibia").await;
+        let new = card(&pool, "Fibula").await;
+        for letters in ["qx", "q", "qz", "x"] {
+            attempt(&pool, hard, false, letters).await;
+        }
+        for _ in 0..3 {
+            attempt(&pool, easy, true, "").await;
+        }
+        attempt(&pool, new, false, "q").await;
+
+        let all = AnalyticsService::list_card_analytics(&pool, &bands, token.clone(), CardAnalyticsQuery::default())
+            .await
+            .unwrap();
+        let flags: Vec<_> = all.items.iter().map(|r| (r.card_id, r.flag.as_deref())).collect();
+        assert_eq!(flags, vec![(hard, Some("TOO_HARD")), (new, None), (easy, Some("TOO_EASY"))]);
+
+        let top: Vec<_> = all.items[0]
+            .top_wrong_letters
+            .iter()
+            .map(|l| (l.letter.as_str(), l.count))
+            .collect();
+        assert_eq!(top, vec![("q", 3), ("x", 2), ("z", 1)]);
+
+        let query = CardAnalyticsQuery {
+            flagged_only: true,
+            ..Default::default()
+        };
+        let flagged = AnalyticsService::list_card_analytics(&pool, &bands, token.clone(), query)
+            .await
+            .unwrap();
+        assert_eq!(flagged.total, 2);
+        assert!(flagged.items.iter().all(|r| r.card_id != new));
+
+        let detail = AnalyticsService::get_card_analytics(&pool, &bands, token.clone(), hard)
+            .await
+            .unwrap();
+        assert_eq!((detail.summary.attempts, detail.wrong_letters.len()), (4, 3));
+
+        let err = AnalyticsService::get_card_analytics(&pool, &bands, token, 9999)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, "CARD_NOT_FOUND");
+    }
+}
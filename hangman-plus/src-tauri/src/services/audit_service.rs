@@ -0,0 +1,92 @@
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+
+use crate::domain::dto::{AuditLogEntry, AuditLogFilter, Page};
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+use crate::utils::paging::page_bounds;
+
+
+// Adds the WHERE clause shared by the count and the page query
+fn push_filters<'a>(qb: &mut QueryBuilder<'a, Sqlite>, filter: &'a AuditLogFilter) {
+    qb.push(" WHERE 1 = 1");
+
+    if let Some(actor_id) = filter.actor_id {
+        qb.push(" AND a.actor_id = ").push_bind(actor_id);
+    }
+    if let Some(action) = filter.action.as_deref().filter(|s| !s.trim().is_empty()) {
+        qb.push(" AND a.action = ").push_bind(action.trim());
+    }
+    if let Some(target_type) = filter.target_type.as_deref().filter(|s| !s.trim().is_empty()) {
+        qb.push(" AND a.target_type = ").push_bind(target_type.trim());
+    }
+    if let Some(target_id) = filter.target_id {
+        qb.push(" AND a.target_id = ").push_bind(target_id);
+    }
+    if let Some(from) = filter.from {
+        qb.push(" AND a.created_at >= ").push_bind(from);
+    }
+    if let Some(to) = filter.to {
+        qb.push(" AND a.created_at < ").push_bind(to);
+    }
+}
+
+pub struct AuditService;
+
+impl AuditService {
+    pub async fn list_audit_log(
+        pool: &SqlitePool,
+        session_token: String,
+        filter: AuditLogFilter,
+    ) -> Result<Page<AuditLogEntry>, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::AuditView).await?;
+
+        let (page, page_size) = page_bounds(filter.page, filter.page_size);
+
+        let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM audit_log a");
+        push_filters(&mut count, &filter);
+
+        let total = count
+            .build_query_scalar::<i64>()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?;
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT
+                a.id,
+                a.actor_id,
+                u.username AS actor_username,
+                a.action,
+                a.target_type,
+                a.target_id,
+                a.before_values,
+                a.after_values,
+                a.created_at
+            FROM audit_log a
+            LEFT JOIN users u ON u.id = a.actor_id
+            "#,
+        );
+        push_filters(&mut query, &filter);
+        query
+            .push(" ORDER BY a.id DESC LIMIT ")
+            .push_bind(page_size)
+            .push(" OFFSET ")
+            .push_bind((page - 1) * page_size);
+
+        let items = query
+            .build_query_as::<AuditLogEntry>()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?;
+
+        Ok(Page {
+            items,
+            total,
+            page,
+            page_size,
+        })
+    }
+}
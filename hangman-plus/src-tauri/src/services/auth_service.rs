@@ -2,14 +2,51 @@ use sqlx::SqlitePool;
 use time::OffsetDateTime;
 use uuid::Uuid;
 
+use crate::config::AppConfig;
+use crate::db::login_attempts;
 use crate::domain::dto::LoginResponse;
+use crate::domain::role::{Permission, Role};
+use crate::errors::AppError;
 use crate::utils::security::{hash_password, hash_token, verify_password};
 
+pub const SESSION_INVALID: &str = "SESSION_INVALID";
+pub const SESSION_EXPIRED: &str = "SESSION_EXPIRED";
+
+// last_seen_at is only rewritten when it is at least this old
+const LAST_SEEN_GRANULARITY_SECS: i64 = 60;
+
+const MAX_DEVICE_LABEL_CHARS: usize = 60;
+
+pub const LOGIN_THROTTLED: &str = "LOGIN_THROTTLED";
+pub const ACCOUNT_LOCKED: &str = "ACCOUNT_LOCKED";
+
 fn now_unix() -> i64 {
     OffsetDateTime::now_utc().unix_timestamp()
 }
 
-fn validate_register(first_name: &str, last_name: &str, username: &str, email: &str, password: &str) -> Result<(), String> {
+fn locked_error(secs_left: i64) -> AppError {
+    let minutes = (secs_left + 59) / 60;
+    AppError::new(
+        ACCOUNT_LOCKED,
+        format!("Too many failed attempts. Login is locked for {minutes} more minute(s)."),
+    )
+}
+
+// Free text from the client, only ever shown back to the same user
+fn clean_device_label(label: Option<String>) -> Option<String> {
+    let label: String = label?.trim().chars().take(MAX_DEVICE_LABEL_CHARS).collect();
+    (!label.is_empty()).then_some(label)
+}
+
+// Rows without limits (should not exist after the init_db backfill) count as expired.
+fn session_expired(now: i64, expires_at: Option<i64>, last_seen_at: i64, idle_timeout: Option<i64>) -> bool {
+    match (expires_at, idle_timeout) {
+        (Some(expires_at), Some(idle)) => now >= expires_at || now - last_seen_at >= idle,
+        _ => true,
+    }
+}
+
+fn validate_register(first_name: &str, last_name: &str, username: &str, email: &str, password: &str) -> Result<(), AppError> {
     if first_name.trim().len() < 2 {
         return Err("First name must be at least 2 characters.".into());
     }
@@ -28,7 +65,7 @@ fn validate_register(first_name: &str, last_name: &str, username: &str, email: &
     Ok(())
 }
 
-fn validate_login(identifier: &str, password: &str) -> Result<(), String> {
+fn validate_login(identifier: &str, password: &str) -> Result<(), AppError> {
     if identifier.trim().is_empty() {
         return Err("Enter username or email.".into());
     }
@@ -40,6 +77,14 @@ fn validate_login(identifier: &str, password: &str) -> Result<(), String> {
 
 pub struct AuthService;
 
+// The session behind a token, for commands that act on sessions themselves
+#[derive(Debug)]
+pub struct SessionInfo {
+    pub session_id: i64,
+    pub user_id: i64,
+    pub role: String,
+}
+
 #[derive(sqlx::FromRow, Debug)]
 struct UserLoginRow {
     pub id: i64,
@@ -55,8 +100,13 @@ struct MeRow {
 
 #[derive(sqlx::FromRow, Debug)]
 struct SessionUserRow {
+    pub session_id: i64,
     pub id: i64,
     pub role: String,
+    pub created_at: i64,
+    pub last_seen_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub idle_timeout: Option<i64>,
 }
 
 
@@ -69,7 +119,7 @@ impl AuthService {
         username: String,
         email: String,
         password: String,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         validate_register(&first_name, &last_name, &username, &email, &password)?;
 
         // unique check
@@ -111,7 +161,9 @@ impl AuthService {
         pool: &SqlitePool,
         identifier: String,
         password: String,
-    ) -> Result<LoginResponse, String> {
+        device_label: Option<String>,
+        config: &AppConfig,
+    ) -> Result<LoginResponse, AppError> {
         validate_login(&identifier, &password)?;
 
         let row = sqlx::query_as::<_, UserLoginRow>(
@@ -126,28 +178,55 @@ impl AuthService {
         .await
         .map_err(|e| format!("DB error: {e}"))?;
 
-        let row = match row {
-            Some(r) => r,
-            None => return Err("Invalid credentials.".into()),
+        // throttled before the password is even checked
+        let user_id = row.as_ref().map(|r| r.id);
+        let attempts_key = login_attempts::key_for(user_id, &identifier);
+        let now = now_unix();
+
+        if let Some(attempts) = login_attempts::get(pool, &attempts_key).await? {
+            if let Some(until) = attempts.locked_until.filter(|&until| until > now) {
+                return Err(locked_error(until - now));
+            }
+            if attempts.next_allowed_at > now {
+                return Err(AppError::new(
+                    LOGIN_THROTTLED,
+                    format!("Too many failed attempts. Try again in {} seconds.", attempts.next_allowed_at - now),
+                ));
+            }
+        }
+
+        let ok = match &row {
+            Some(r) => verify_password(&password, &r.password_hash)?,
+            None => false,
         };
 
-        let ok = verify_password(&password, &row.password_hash)?;
-        if !ok {
+        let Some(row) = row.filter(|_| ok) else {
+            let attempts =
+                login_attempts::record_failure(pool, &attempts_key, user_id, &config.login_throttle, now).await?;
+            if let Some(until) = attempts.locked_until {
+                return Err(locked_error(until - now));
+            }
             return Err("Invalid credentials.".into());
-        }
+        };
 
+        login_attempts::clear(pool, &attempts_key).await?;
+
+        let policy = &config.sessions;
         let session_token = Uuid::new_v4().to_string();
         let token_hash = hash_token(&session_token);
 
         sqlx::query(
             r#"
-            INSERT INTO sessions (user_id, token_hash, created_at, revoked_at)
-            VALUES (?1, ?2, ?3, NULL)
+            INSERT INTO sessions (user_id, token_hash, created_at, revoked_at, last_seen_at, expires_at, idle_timeout, label)
+            VALUES (?1, ?2, ?3, NULL, ?3, ?4, ?5, ?6)
             "#,
         )
         .bind(row.id)
         .bind(token_hash)
-        .bind(now_unix())
+        .bind(now)
+        .bind(now + policy.absolute_secs())
+        .bind(policy.idle_secs())
+        .bind(clean_device_label(device_label))
         .execute(pool)
         .await
         .map_err(|e| format!("Session create failed: {e}"))?;
@@ -155,28 +234,19 @@ impl AuthService {
         Ok(LoginResponse { session_token })
     }
 
-    pub async fn validate_session(pool: &SqlitePool, session_token: String) -> Result<bool, String> {
+    pub async fn validate_session(pool: &SqlitePool, session_token: String) -> Result<bool, AppError> {
         if session_token.trim().is_empty() {
             return Ok(false);
         }
-        let token_hash = hash_token(session_token.trim());
 
-        let exists = sqlx::query_scalar::<_, i64>(
-            r#"
-            SELECT COUNT(*)
-            FROM sessions
-            WHERE token_hash = ?1 AND revoked_at IS NULL
-            "#,
-        )
-        .bind(token_hash)
-        .fetch_one(pool)
-        .await
-        .map_err(|e| format!("DB error: {e}"))?;
-
-        Ok(exists > 0)
+        match Self::require_session_user(pool, &session_token).await {
+            Ok(_) => Ok(true),
+            Err(e) if e.code == SESSION_INVALID || e.code == SESSION_EXPIRED => Ok(false),
+            Err(e) => Err(e),
+        }
     }
 
-    pub async fn logout(pool: &SqlitePool, session_token: String) -> Result<(), String> {
+    pub async fn logout(pool: &SqlitePool, session_token: String) -> Result<(), AppError> {
         let token_hash = hash_token(session_token.trim());
 
         sqlx::query(
@@ -195,48 +265,53 @@ impl AuthService {
         Ok(())
     }
     
-    pub async fn get_me(pool: &SqlitePool, session_token: String) -> Result<crate::domain::dto::MeResponse, String> {
-    if session_token.trim().is_empty() {
-        return Err("Missing session token.".into());
-    }
+    pub async fn get_me(pool: &SqlitePool, session_token: String) -> Result<crate::domain::dto::MeResponse, AppError> {
+    let (user_id, _role) = Self::require_session_user(pool, &session_token).await?;
 
-    let token_hash = hash_token(session_token.trim());
-
-    let row = sqlx::query_as::<_, MeRow>(
+    let r = sqlx::query_as::<_, MeRow>(
         r#"
         SELECT u.id as "id", u.username as "username", u.role as "role"
-        FROM sessions s
-        JOIN users u ON u.id = s.user_id
-        WHERE s.token_hash = ?1 AND s.revoked_at IS NULL
-        ORDER BY s.created_at DESC
-        LIMIT 1
+        FROM users u
+        WHERE u.id = ?1
         "#
     )
-    .bind(token_hash)
-    .fetch_optional(pool)
+    .bind(user_id)
+    .fetch_one(pool)
     .await
     .map_err(|e| format!("DB error: {e}"))?;
 
-    match row {
-        Some(r) => Ok(crate::domain::dto::MeResponse {
-            id: r.id,
-            username: r.username,
-            role: r.role,
-        }),
-        None => Err("Invalid session.".into()),
-    }
+    Ok(crate::domain::dto::MeResponse {
+        id: r.id,
+        username: r.username,
+        permissions: Role::parse(&r.role)
+            .unwrap_or(Role::User)
+            .permissions()
+            .iter()
+            .map(|p| p.as_str().to_string())
+            .collect(),
+        role: r.role,
+    })
+}
+
+pub async fn require_session_user(pool: &SqlitePool, session_token: &str) -> Result<(i64, String), AppError> {
+    let session = Self::require_session(pool, session_token).await?;
+    Ok((session.user_id, session.role))
 }
 
-pub async fn require_session_user(pool: &SqlitePool, session_token: &str) -> Result<(i64, String), String> {
+// Every authenticated command goes through here. Expired sessions get their
+// own error code so the UI can send the user back to login.
+pub async fn require_session(pool: &SqlitePool, session_token: &str) -> Result<SessionInfo, AppError> {
     if session_token.trim().is_empty() {
-        return Err("Missing session token.".into());
+        return Err(AppError::new(SESSION_INVALID, "Missing session token."));
     }
 
     let token_hash = hash_token(session_token.trim());
 
     let row = sqlx::query_as::<_, SessionUserRow>(
         r#"
-        SELECT u.id as "id", u.role as "role"
+        SELECT s.id as "session_id", u.id as "id", u.role as "role",
+               s.created_at as "created_at", s.last_seen_at as "last_seen_at",
+               s.expires_at as "expires_at", s.idle_timeout as "idle_timeout"
         FROM sessions s
         JOIN users u ON u.id = s.user_id
         WHERE s.token_hash = ?1 AND s.revoked_at IS NULL
@@ -247,14 +322,114 @@ pub async fn require_session_user(pool: &SqlitePool, session_token: &str) -> Res
     .bind(token_hash)
     .fetch_optional(pool)
     .await
-    .map_err(|e| format!("DB error: {e}"))?;
+    .map_err(|e| format!("DB error: {e}"))?
+    .ok_or_else(|| AppError::new(SESSION_INVALID, "Invalid session."))?;
+
+    let now = now_unix();
+    let last_seen = row.last_seen_at.unwrap_or(row.created_at);
 
-    match row {
-        Some(r) => Ok((r.id, r.role)),
-        None => Err("Invalid session.".into()),
+    if session_expired(now, row.expires_at, last_seen, row.idle_timeout) {
+        return Err(AppError::new(SESSION_EXPIRED, "Your session has expired. Please log in again."));
     }
+
+    // sliding idle window; skip the write when the session was just used
+    if now - last_seen >= LAST_SEEN_GRANULARITY_SECS {
+        sqlx::query(r#"UPDATE sessions SET last_seen_at = ?2 WHERE id = ?1"#)
+            .bind(row.session_id)
+            .bind(now)
+            .execute(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?;
+    }
+
+    Ok(SessionInfo {
+        session_id: row.session_id,
+        user_id: row.id,
+        role: row.role,
+    })
+}
+
+// Unknown role strings get no permissions.
+pub async fn require_permission(pool: &SqlitePool, session_token: &str, permission: Permission) -> Result<(i64, Role), AppError> {
+    let (user_id, role) = Self::require_session_user(pool, session_token).await?;
+    let role = Role::parse(&role).unwrap_or(Role::User);
+
+    if !role.can(permission) {
+        return Err(AppError::new(
+            "FORBIDDEN",
+            format!("Forbidden: missing permission {}.", permission.as_str()),
+        ));
+    }
+
+    Ok((user_id, role))
 }
 
 
     
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LoginThrottle;
+    use crate::db::test_util::{login_as, temp_pool};
+
+    #[test]
+    fn session_expires_on_absolute_or_idle_limit() {
+        // logged in at 0, lives 1000s, 100s idle window
+        assert!(!session_expired(50, Some(1000), 0, Some(100)));
+        assert!(session_expired(150, Some(1000), 0, Some(100)));
+        assert!(!session_expired(950, Some(1000), 900, Some(100)));
+        assert!(session_expired(1000, Some(1000), 990, Some(100)));
+        assert!(session_expired(10, None, 0, None));
+    }
+
+    // no backoff, lock on the third failure
+    fn strict_config() -> AppConfig {
+        AppConfig {
+            login_throttle: LoginThrottle {
+                free_attempts: 100,
+                lockout_threshold: 3,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    async fn login(pool: &SqlitePool, password: &str, config: &AppConfig) -> Result<LoginResponse, AppError> {
+        AuthService::login_user(pool, "student".into(), password.into(), None, config).await
+    }
+
+    #[tokio::test]
+    async fn account_locks_after_the_threshold() {
+        let (_dir, pool) = temp_pool().await;
+        login_as(&pool, "student", "USER").await;
+        let config = strict_config();
+
+        for _ in 0..2 {
+            let err = login(&pool, "wrong-password", &config).await.err().unwrap();
+            assert_eq!(err.code, "ERROR");
+        }
+        let err = login(&pool, "wrong-password", &config).await.err().unwrap();
+        assert_eq!(err.code, ACCOUNT_LOCKED);
+
+        // the right password does not get through a lock either
+        let err = login(&pool, "password1", &config).await.err().unwrap();
+        assert_eq!(err.code, ACCOUNT_LOCKED);
+    }
+
+    #[tokio::test]
+    async fn success_resets_the_failure_count() {
+        let (_dir, pool) = temp_pool().await;
+        login_as(&pool, "student", "USER").await;
+        let config = strict_config();
+
+        for _ in 0..2 {
+            assert!(login(&pool, "wrong-password", &config).await.is_err());
+        }
+        login(&pool, "password1", &config).await.unwrap();
+
+        let err = login(&pool, "wrong-password", &config).await.err().unwrap();
+        assert_ne!(err.code, ACCOUNT_LOCKED);
+    }
+}
@@ -0,0 +1,186 @@
+use hangman_core::bot::{self, BotLevel};
+use sqlx::SqlitePool;
+use time::OffsetDateTime;
+
+use crate::db;
+use crate::domain::category::Category;
+use crate::domain::dto::{BotRecord, BotRoundInput, BotRoundResult};
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+const MAX_WRONG_LIMIT: i64 = 10;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+#[derive(sqlx::FromRow)]
+struct CardTerms {
+    category: String,
+    english: String,
+    latin: String,
+}
+
+pub struct BotService;
+
+impl BotService {
+    // The bot plays the card the player just finished, under the same rules,
+    // and the round is stored with the player's result.
+    pub async fn play_bot_round(pool: &SqlitePool, input: BotRoundInput) -> Result<BotRoundResult, AppError> {
+        let (user_id, _role) = AuthService::require_session_user(pool, &input.session_token).await?;
+
+        let level = BotLevel::parse(&input.level)
+            .ok_or_else(|| AppError::new("BOT_LEVEL_INVALID", "Bot level must be EASY, MEDIUM or HARD."))?;
+
+        if !(1..=MAX_WRONG_LIMIT).contains(&input.max_wrong)
+            || !(0..=input.max_wrong).contains(&input.player_wrong)
+        {
+            return Err(AppError::new("BOT_ROUND_INVALID", "Wrong guess counts are out of range."));
+        }
+
+        // only published cards, so the bot's guesses can't leak an unreviewed term
+        let card = sqlx::query_as::<_, CardTerms>(
+            r#"
+            SELECT category, english, latin
+            FROM cards
+            WHERE id = ?1
+              AND status = 'APPROVED'
+              AND archived_at IS NULL
+            "#,
+        )
+        .bind(input.card_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?
+        .ok_or_else(|| AppError::new("CARD_NOT_FOUND", "Card not found."))?;
+
+        let category = Category::parse(&card.category)
+            .ok_or_else(|| format!("Unknown category in DB: {}", card.category))?;
+
+        let language = input.language.trim().to_uppercase();
+        let answer = match language.as_str() {
+            "EN" => &card.english,
+            "LAT" => &card.latin,
+            _ => return Err(AppError::new("LANGUAGE_INVALID", "Language must be EN or LAT.")),
+        };
+
+        // the bot knows the deck, like a player who has seen the cards before
+        let corpus = db::cards::get_terms(pool, category, &language).await?;
+
+        let round = bot::play_round(answer, &corpus, level, input.max_wrong as usize, rand::random());
+        let outcome = bot::decide(input.player_solved, input.player_wrong as usize, &round);
+        let guesses: String = round.guesses.iter().collect();
+
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO bot_matches
+              (user_id, game_id, card_id, language, level, max_wrong, player_wrong, player_solved,
+               bot_wrong, bot_solved, bot_guesses, outcome, played_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
+            RETURNING id
+            "#,
+        )
+        .bind(user_id)
+        .bind(input.game_id.as_deref())
+        .bind(input.card_id)
+        .bind(&language)
+        .bind(level.as_str())
+        .bind(input.max_wrong)
+        .bind(input.player_wrong)
+        .bind(input.player_solved)
+        .bind(round.wrong_count as i64)
+        .bind(round.solved)
+        .bind(&guesses)
+        .bind(outcome.as_str())
+        .bind(now_unix())
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("Save bot round failed: {e}"))?;
+
+        Ok(BotRoundResult {
+            id,
+            bot_guesses: round.guesses.iter().map(|c| c.to_string()).collect(),
+            bot_wrong: round.wrong_count as i64,
+            bot_solved: round.solved,
+            outcome: outcome.as_str().to_string(),
+        })
+    }
+
+    pub async fn get_bot_record(pool: &SqlitePool, session_token: String) -> Result<Vec<BotRecord>, AppError> {
+        let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+
+        sqlx::query_as::<_, BotRecord>(
+            r#"
+            SELECT
+                level,
+                COUNT(*) AS played,
+                COALESCE(SUM(outcome = 'PLAYER'), 0) AS wins,
+                COALESCE(SUM(outcome = 'BOT'), 0) AS losses,
+                COALESCE(SUM(outcome = 'DRAW'), 0) AS draws
+            FROM bot_matches
+            WHERE user_id = ?1
+            GROUP BY level
+            ORDER BY CASE level WHEN 'EASY' THEN 1 WHEN 'MEDIUM' THEN 2 ELSE 3 END
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}").into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::{login_as, temp_pool};
+
+    async fn card(pool: &SqlitePool, english: &str, status: &str, archived_at: Option<i64>) -> i64 {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at, archived_at)
+            VALUES ('BONES', ?1, ?1, '/cards/bones/test.png', ?2, 0, ?3)
+            RETURNING id
+            "#,
+        )
+        .bind(english)
+        .bind(status)
+        .bind(archived_at)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    fn round(session_token: &str, card_id: i64) -> BotRoundInput {
+        BotRoundInput {
+            session_token: session_token.to_string(),
+            game_id: None,
+            card_id,
+            language: "EN".into(),
+            level: "HARD".into(),
+            max_wrong: 6,
+            player_wrong: 0,
+            player_solved: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn unpublished_cards_cannot_be_played_against_the_bot() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "player", "USER").await;
+
+        let approved = card(&pool, "Femur", "APPROVED", None).await;
+        let hidden = [
+            card(&pool, "Tibia", "PENDING", None).await,
+            card(&pool, "Fibula", "REJECTED", None).await,
+            card(&pool, "Patella", "APPROVED", Some(1)).await,
+        ];
+
+        BotService::play_bot_round(&pool, round(&token, approved)).await.unwrap();
+
+        for id in hidden {
+            let err = BotService::play_bot_round(&pool, round(&token, id)).await.err().unwrap();
+            assert_eq!(err.code, "CARD_NOT_FOUND");
+        }
+    }
+}
@@ -1,139 +1,688 @@
-use sqlx::SqlitePool;
+use std::collections::HashSet;
+
+use sqlx::types::Json;
+use sqlx::{Connection, QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
 use time::OffsetDateTime;
 
 use crate::domain::dto::{CardResponse, CreateCardInput};
 use crate::services::auth_service::AuthService;
-use crate::domain::dto::{CardAdminItem, UpdateCardInput};
+use crate::domain::dto::{
+    ArchivedCard, BatchReviewResult, CardAdminItem, CardListQuery, CardRevision, CardSnapshot,
+    DuplicateMatch, MissingImageCard, MyCardRequest, Page, PendingCard, PurgeCardResult,
+    ResubmitCardInput, UpdateCardInput,
+};
+use crate::services::duplicate_service::{self, DuplicateService, NewCardTerms};
+use crate::domain::card::CardStatus;
+use crate::domain::category::Category;
+use crate::domain::validation::{validate_card, CardFields, FieldError};
+use crate::db::audit_log::{self, json};
+use crate::db::card_revisions;
+use crate::domain::region::Region;
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::utils::assets::AssetRoots;
+use crate::utils::paging::page_bounds;
 
 
 fn now_unix() -> i64 {
     OffsetDateTime::now_utc().unix_timestamp()
 }
 
+// Region is stored as JSON text next to the card; validate_card checks it first
+pub fn region_json(region: Option<&Region>) -> Result<Option<String>, String> {
+    region
+        .map(serde_json::to_string)
+        .transpose()
+        .map_err(|e| format!("Region encode failed: {e}"))
+}
+
+fn clean_comment(comment: Option<String>) -> Option<String> {
+    comment
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+}
+
+// Input that passed the shared validator and the asset root check
+pub struct CheckedCard {
+    pub category: Option<Category>,
+    pub english: String,
+    pub latin: String,
+    pub image_path: String,
+    pub region: Option<String>,
+}
+
+// Every card write path goes through here so the UI gets the same
+// field-level errors no matter which form sent the card.
+pub fn check_card(assets: &AssetRoots, fields: CardFields<'_>) -> Result<CheckedCard, Vec<FieldError>> {
+    let image_path = fields.image_path.unwrap_or_default();
+
+    let (valid, mut errors) = match validate_card(&fields) {
+        Ok(v) => (Some(v), Vec::new()),
+        Err(errors) => (None, errors),
+    };
+
+    let image_path = if image_path.trim().is_empty() {
+        None // already reported by the validator
+    } else {
+        match assets.validate(image_path) {
+            Ok(p) => Some(p),
+            Err(e) => {
+                errors.push(FieldError::new("imagePath", e.code(), e.to_string()));
+                None
+            }
+        }
+    };
+
+    let region = match region_json(fields.region) {
+        Ok(r) => r,
+        Err(msg) => {
+            errors.push(FieldError::new("region", "REGION_INVALID", msg));
+            None
+        }
+    };
+
+    match (valid, image_path) {
+        (Some(v), Some(image_path)) if errors.is_empty() => Ok(CheckedCard {
+            category: v.category,
+            english: v.english,
+            latin: v.latin,
+            image_path,
+            region,
+        }),
+        _ => Err(errors),
+    }
+}
+
+// Likely duplicates of a card plus the JSON kept on the card for the pending list
+async fn find_duplicates(
+    pool: &SqlitePool,
+    card: &CheckedCard,
+    image_hash: Option<&str>,
+    exclude_id: Option<i64>,
+) -> Result<(Vec<DuplicateMatch>, Option<String>), AppError> {
+    let terms = NewCardTerms {
+        category: card.category.ok_or("Category is required.")?,
+        english: &card.english,
+        latin: &card.latin,
+        image_hash,
+        has_region: card.region.is_some(),
+    };
+    let matches = DuplicateService::find_duplicates(pool, terms, exclude_id).await?;
+
+    let json = if matches.is_empty() {
+        None
+    } else {
+        Some(serde_json::to_string(&matches).map_err(|e| format!("Encode failed: {e}"))?)
+    };
+
+    Ok((matches, json))
+}
+
+const MAX_BATCH: usize = 200;
+
+#[derive(Debug, Clone, Copy)]
+enum Decision {
+    Approve,
+    Reject,
+}
+
+impl Decision {
+    fn status(self) -> &'static str {
+        match self {
+            Decision::Approve => "APPROVED",
+            Decision::Reject => "REJECTED",
+        }
+    }
+
+    fn action(self) -> &'static str {
+        match self {
+            Decision::Approve => "APPROVE",
+            Decision::Reject => "REJECT",
+        }
+    }
+}
+
+// Sets the review decision on one card, with revision and audit entries.
+// Runs on the caller's transaction.
+async fn review_card(
+    conn: &mut SqliteConnection,
+    user_id: i64,
+    card_id: i64,
+    decision: Decision,
+    comment: Option<&str>,
+) -> Result<(), AppError> {
+    let old = card_revisions::snapshot(&mut *conn, card_id)
+        .await?
+        .ok_or("Card not found.")?;
+
+    // only live pending cards; an approved, rejected or archived card stays as is
+    let res = sqlx::query(
+        r#"
+        UPDATE cards
+        SET status = ?1,
+            review_comment = ?2,
+            reviewed_by = ?3,
+            reviewed_at = ?4,
+            decision_seen = 0
+        WHERE id = ?5 AND status = 'PENDING' AND archived_at IS NULL
+        "#,
+    )
+    .bind(decision.status())
+    .bind(comment)
+    .bind(user_id)
+    .bind(now_unix())
+    .bind(card_id)
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| format!("Review failed: {e}"))?;
+
+    if res.rows_affected() == 0 {
+        return Err(AppError::new("CARD_NOT_PENDING", "Card is not pending review."));
+    }
+
+    let action = decision.action();
+    let new = card_revisions::record(&mut *conn, card_id, action, user_id, Some(&old), comment).await?;
+    audit_log::record(
+        &mut *conn,
+        user_id,
+        &format!("CARD_{action}"),
+        "CARD",
+        Some(card_id),
+        json(&old),
+        json(&new),
+    )
+    .await?;
+
+    Ok(())
+}
+
+// Only whitelisted ORDER BY clauses; id breaks ties so pages don't overlap
+fn sort_clause(sort: Option<&str>) -> Result<&'static str, AppError> {
+    match sort.map(|s| s.trim().to_uppercase()).as_deref() {
+        None | Some("") | Some("NEWEST") => Ok(" ORDER BY c.created_at DESC, c.id DESC"),
+        Some("OLDEST") => Ok(" ORDER BY c.created_at ASC, c.id ASC"),
+        Some("ENGLISH") => Ok(" ORDER BY c.english COLLATE NOCASE ASC, c.id ASC"),
+        Some("LATIN") => Ok(" ORDER BY c.latin COLLATE NOCASE ASC, c.id ASC"),
+        Some("CATEGORY") => Ok(" ORDER BY c.category ASC, c.english COLLATE NOCASE ASC, c.id ASC"),
+        Some(other) => Err(format!("Unknown sort order: {other}.").into()),
+    }
+}
+
+// LIKE pattern for a contains-search; % and _ in the input match literally
+fn like_pattern(search: &str) -> String {
+    let escaped = search
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+// Adds the WHERE clause shared by the count and the page query
+fn push_card_filters<'a>(
+    qb: &mut QueryBuilder<'a, Sqlite>,
+    query: &'a CardListQuery,
+    status: Option<CardStatus>,
+    category: Option<Category>,
+) {
+    qb.push(" WHERE c.archived_at IS NULL");
+
+    if let Some(status) = status {
+        qb.push(" AND c.status = ").push_bind(status.as_str());
+    }
+    if let Some(category) = category {
+        qb.push(" AND c.category = ").push_bind(category.as_str());
+    }
+    if let Some(created_by) = query.created_by {
+        qb.push(" AND c.created_by = ").push_bind(created_by);
+    }
+    if let Some(from) = query.from {
+        qb.push(" AND c.created_at >= ").push_bind(from);
+    }
+    if let Some(to) = query.to {
+        qb.push(" AND c.created_at < ").push_bind(to);
+    }
+    if let Some(search) = query.search.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        let pattern = like_pattern(search);
+        qb.push(" AND (c.english LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\' OR c.latin LIKE ")
+            .push_bind(pattern.clone())
+            .push(" ESCAPE '\\' OR c.synonyms LIKE ")
+            .push_bind(pattern)
+            .push(" ESCAPE '\\')");
+    }
+}
+
+async fn count_cards(
+    pool: &SqlitePool,
+    query: &CardListQuery,
+    status: Option<CardStatus>,
+    category: Option<Category>,
+) -> Result<i64, AppError> {
+    let mut count = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM cards c");
+    push_card_filters(&mut count, query, status, category);
+
+    count
+        .build_query_scalar::<i64>()
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}").into())
+}
+
 pub struct CardService;
 
 impl CardService {
     pub async fn add_card_admin(
         pool: &SqlitePool,
+        assets: &AssetRoots,
         input: CreateCardInput,
-    ) -> Result<CardResponse, String> {
-        let (user_id, role) =
-            AuthService::require_session_user(pool, &input.sessionToken).await?;
-        if role != "ADMIN" {
-            return Err("Forbidden: admin only.".into());
-        }
+    ) -> Result<CardResponse, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &input.sessionToken, Permission::CardsEdit).await?;
+
+        let card = check_card(
+            assets,
+            CardFields {
+                category: Some(&input.category),
+                english: &input.english,
+                latin: &input.latin,
+                image_path: Some(&input.imagePath),
+                region: input.region.as_ref(),
+            },
+        )
+        .map_err(AppError::validation)?;
+
+        let image_hash = duplicate_service::image_hash(assets, &card.image_path);
+
+        let (duplicates, _) = find_duplicates(pool, &card, image_hash.as_deref(), None).await?;
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
 
         let id = sqlx::query_scalar::<_, i64>(
             r#"
-            INSERT INTO cards (category, english, latin, image_path, status, created_by, created_at)
-            VALUES (?1, ?2, ?3, ?4, 'APPROVED', ?5, ?6)
+            INSERT INTO cards (category, english, latin, image_path, region, image_hash, status, created_by, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'APPROVED', ?7, ?8)
             RETURNING id
             "#,
         )
-        .bind(input.category.trim())
-        .bind(input.english.trim())
-        .bind(input.latin.trim())
-        .bind(input.imagePath.trim())
+        .bind(card.category.map(|c| c.as_str()))
+        .bind(&card.english)
+        .bind(&card.latin)
+        .bind(&card.image_path)
+        .bind(&card.region)
+        .bind(image_hash)
         .bind(user_id)
         .bind(now_unix())
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| format!("Insert failed: {e}"))?;
 
+        let new = card_revisions::record(&mut tx, id, "CREATE", user_id, None, None).await?;
+        audit_log::record(&mut tx, user_id, "CARD_CREATE", "CARD", Some(id), None, json(&new)).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
         Ok(CardResponse {
             id,
             status: "APPROVED".into(),
+            duplicates,
         })
     }
 
     pub async fn request_card_user(
         pool: &SqlitePool,
+        assets: &AssetRoots,
         input: CreateCardInput,
-    ) -> Result<CardResponse, String> {
+    ) -> Result<CardResponse, AppError> {
         let (user_id, _role) =
             AuthService::require_session_user(pool, &input.sessionToken).await?;
 
+        let card = check_card(
+            assets,
+            CardFields {
+                category: Some(&input.category),
+                english: &input.english,
+                latin: &input.latin,
+                image_path: Some(&input.imagePath),
+                region: input.region.as_ref(),
+            },
+        )
+        .map_err(AppError::validation)?;
+
+        let image_hash = duplicate_service::image_hash(assets, &card.image_path);
+
+        let (duplicates, duplicates_json) =
+            find_duplicates(pool, &card, image_hash.as_deref(), None).await?;
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
         let id = sqlx::query_scalar::<_, i64>(
             r#"
-            INSERT INTO cards (category, english, latin, image_path, status, created_by, created_at)
-            VALUES (?1, ?2, ?3, ?4, 'PENDING', ?5, ?6)
+            INSERT INTO cards (category, english, latin, image_path, region, image_hash, possible_duplicates, status, created_by, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 'PENDING', ?8, ?9)
             RETURNING id
             "#,
         )
-        .bind(input.category.trim())
-        .bind(input.english.trim())
-        .bind(input.latin.trim())
-        .bind(input.imagePath.trim())
+        .bind(card.category.map(|c| c.as_str()))
+        .bind(&card.english)
+        .bind(&card.latin)
+        .bind(&card.image_path)
+        .bind(&card.region)
+        .bind(image_hash)
+        .bind(duplicates_json)
         .bind(user_id)
         .bind(now_unix())
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| format!("Request create failed: {e}"))?;
 
+        card_revisions::record(&mut tx, id, "CREATE", user_id, None, None).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
         Ok(CardResponse {
             id,
             status: "PENDING".into(),
+            duplicates,
         })
     }
 
-    pub async fn approve_card(
+    // The previous review stays on the card (and in the RESUBMIT revision note)
+    // so the admin sees what was asked for last time.
+    pub async fn resubmit_card_request(
         pool: &SqlitePool,
-        sessionToken: String,
-        cardId: i64,
-    ) -> Result<(), String> {
-        let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-        if role != "ADMIN" {
-            return Err("Forbidden: admin only.".into());
-        }
+        assets: &AssetRoots,
+        input: ResubmitCardInput,
+    ) -> Result<CardResponse, AppError> {
+        let (user_id, _role) =
+            AuthService::require_session_user(pool, &input.session_token).await?;
+
+        let card = check_card(
+            assets,
+            CardFields {
+                category: Some(&input.category),
+                english: &input.english,
+                latin: &input.latin,
+                image_path: Some(&input.image_path),
+                region: input.region.as_ref(),
+            },
+        )
+        .map_err(AppError::validation)?;
 
-        let rows = sqlx::query(r#"UPDATE cards SET status = 'APPROVED' WHERE id = ?1"#)
-            .bind(cardId)
-            .execute(pool)
+        let mut tx = pool
+            .begin()
             .await
-            .map_err(|e| format!("Approve failed: {e}"))?
-            .rows_affected();
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let existing = sqlx::query_as::<_, (i64, String, Option<i64>, Option<String>)>(
+            r#"SELECT created_by, status, archived_at, review_comment FROM cards WHERE id = ?1"#,
+        )
+        .bind(input.card_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
 
-        if rows == 0 {
+        let Some((created_by, status, archived_at, review_comment)) = existing else {
             return Err("Card not found.".into());
+        };
+        if created_by != user_id {
+            return Err("Forbidden: not your card.".into());
+        }
+        if archived_at.is_some() || !(status == "PENDING" || status == "REJECTED") {
+            return Err(AppError::new(
+                "CARD_NOT_EDITABLE",
+                "Only pending or rejected requests can be edited.",
+            ));
         }
 
-        Ok(())
+        // only the owner of an editable request gets this far
+        let image_hash = duplicate_service::image_hash(assets, &card.image_path);
+        let (duplicates, duplicates_json) =
+            find_duplicates(pool, &card, image_hash.as_deref(), Some(input.card_id)).await?;
+
+        let old = card_revisions::snapshot(&mut tx, input.card_id).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE cards
+            SET category = ?1,
+                english = ?2,
+                latin = ?3,
+                image_path = ?4,
+                region = ?5,
+                possible_duplicates = ?6,
+                image_hash = ?7,
+                status = 'PENDING',
+                decision_seen = 1
+            WHERE id = ?8
+            "#,
+        )
+        .bind(card.category.map(|c| c.as_str()))
+        .bind(&card.english)
+        .bind(&card.latin)
+        .bind(&card.image_path)
+        .bind(&card.region)
+        .bind(duplicates_json)
+        .bind(image_hash)
+        .bind(input.card_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Resubmit failed: {e}"))?;
+
+        let note = review_comment.map(|c| format!("Previous review: {c}"));
+        card_revisions::record(&mut tx, input.card_id, "RESUBMIT", user_id, old.as_ref(), note.as_deref())
+            .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
+        Ok(CardResponse {
+            id: input.card_id,
+            status: "PENDING".into(),
+            duplicates,
+        })
+    }
+
+    pub async fn approve_card(
+        pool: &SqlitePool,
+        sessionToken: String,
+        cardId: i64,
+        comment: Option<String>,
+    ) -> Result<(), AppError> {
+        Self::review_one(pool, &sessionToken, cardId, Decision::Approve, comment).await
     }
 
     pub async fn reject_card(
         pool: &SqlitePool,
         sessionToken: String,
         cardId: i64,
-    ) -> Result<(), String> {
-        let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-        if role != "ADMIN" {
-            return Err("Forbidden: admin only.".into());
+        comment: Option<String>,
+    ) -> Result<(), AppError> {
+        Self::review_one(pool, &sessionToken, cardId, Decision::Reject, comment).await
+    }
+
+    pub async fn approve_cards(
+        pool: &SqlitePool,
+        session_token: String,
+        card_ids: Vec<i64>,
+        comment: Option<String>,
+    ) -> Result<Vec<BatchReviewResult>, AppError> {
+        Self::review_batch(pool, &session_token, card_ids, Decision::Approve, comment).await
+    }
+
+    pub async fn reject_cards(
+        pool: &SqlitePool,
+        session_token: String,
+        card_ids: Vec<i64>,
+        comment: Option<String>,
+    ) -> Result<Vec<BatchReviewResult>, AppError> {
+        Self::review_batch(pool, &session_token, card_ids, Decision::Reject, comment).await
+    }
+
+    async fn review_one(
+        pool: &SqlitePool,
+        session_token: &str,
+        card_id: i64,
+        decision: Decision,
+        comment: Option<String>,
+    ) -> Result<(), AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, session_token, Permission::CardsReview).await?;
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let comment = clean_comment(comment);
+        review_card(&mut tx, user_id, card_id, decision, comment.as_deref()).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
+        Ok(())
+    }
+
+    // One session check and one transaction for the whole list. Every id gets
+    // its own savepoint, so a missing card is reported without undoing the rest.
+    async fn review_batch(
+        pool: &SqlitePool,
+        session_token: &str,
+        card_ids: Vec<i64>,
+        decision: Decision,
+        comment: Option<String>,
+    ) -> Result<Vec<BatchReviewResult>, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, session_token, Permission::CardsReview).await?;
+
+        if card_ids.is_empty() {
+            return Err("Select at least one card.".into());
+        }
+        if card_ids.len() > MAX_BATCH {
+            return Err(format!("At most {MAX_BATCH} cards can be reviewed at once.").into());
         }
 
-        let rows = sqlx::query(r#"UPDATE cards SET status = 'REJECTED' WHERE id = ?1"#)
-            .bind(cardId)
-            .execute(pool)
+        let comment = clean_comment(comment);
+        let mut seen = HashSet::new();
+        let mut results = Vec::with_capacity(card_ids.len());
+
+        let mut tx = pool
+            .begin()
             .await
-            .map_err(|e| format!("Reject failed: {e}"))?
-            .rows_affected();
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
 
-        if rows == 0 {
-            return Err("Card not found.".into());
+        for card_id in card_ids {
+            if !seen.insert(card_id) {
+                results.push(BatchReviewResult::failed(card_id, "Card is listed twice."));
+                continue;
+            }
+
+            let mut savepoint = Connection::begin(&mut *tx)
+                .await
+                .map_err(|e| format!("Begin savepoint failed: {e}"))?;
+
+            match review_card(&mut savepoint, user_id, card_id, decision, comment.as_deref()).await {
+                Ok(()) => {
+                    savepoint
+                        .commit()
+                        .await
+                        .map_err(|e| format!("Commit failed: {e}"))?;
+                    results.push(BatchReviewResult {
+                        card_id,
+                        ok: true,
+                        status: Some(decision.status().to_string()),
+                        error: None,
+                    });
+                }
+                // dropping the savepoint rolls back this card only
+                Err(e) => results.push(BatchReviewResult::failed(card_id, e)),
+            }
         }
 
-        Ok(())
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
+        Ok(results)
     }
 
     pub async fn list_pending_cards(
     pool: &SqlitePool,
     sessionToken: String,
-) -> Result<Vec<crate::domain::dto::PendingCard>, String> {
-    let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-    if role != "ADMIN" {
-        return Err("Forbidden: admin only.".into());
-    }
+    query: CardListQuery,
+) -> Result<Page<PendingCard>, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &sessionToken, Permission::CardsReview).await?;
+
+    let category = Category::parse_filter(query.category.as_deref())?;
+    let order_by = sort_clause(query.sort.as_deref())?;
+    let (page, page_size) = page_bounds(query.page, query.page_size);
+    let total = count_cards(pool, &query, Some(CardStatus::Pending), category).await?;
 
-    let rows = sqlx::query_as::<_, crate::domain::dto::PendingCard>(
+    let mut qb = QueryBuilder::<Sqlite>::new(
+        r#"
+        SELECT
+            c.id,
+            c.category,
+            c.english,
+            c.latin,
+            c.image_path,
+            c.region,
+            c.status,
+            c.created_by,
+            c.created_at,
+            c.review_comment,
+            c.reviewed_at,
+            c.possible_duplicates,
+            (
+                SELECT COUNT(*)
+                FROM card_revisions r
+                WHERE r.card_id = c.id AND r.action = 'RESUBMIT'
+            ) AS resubmit_count
+        FROM cards c
+        "#,
+    );
+    push_card_filters(&mut qb, &query, Some(CardStatus::Pending), category);
+    qb.push(order_by)
+        .push(" LIMIT ")
+        .push_bind(page_size)
+        .push(" OFFSET ")
+        .push_bind((page - 1) * page_size);
+
+    let items = qb
+        .build_query_as::<PendingCard>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(Page {
+        items,
+        total,
+        page,
+        page_size,
+    })
+}
+
+// Cards the current user submitted, newest first, with the reviewer's feedback
+pub async fn list_my_card_requests(
+    pool: &SqlitePool,
+    session_token: String,
+) -> Result<Vec<MyCardRequest>, AppError> {
+    let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+
+    let rows = sqlx::query_as::<_, MyCardRequest>(
         r#"
         SELECT
             id,
@@ -142,13 +691,16 @@ impl CardService {
             latin,
             image_path,
             status,
-            created_by,
-            created_at
+            created_at,
+            review_comment,
+            reviewed_at,
+            decision_seen
         FROM cards
-        WHERE status = 'PENDING'
-        ORDER BY created_at DESC
+        WHERE created_by = ?1
+        ORDER BY created_at DESC, id DESC
         "#,
     )
+    .bind(user_id)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("DB error: {e}"))?;
@@ -156,17 +708,61 @@ impl CardService {
     Ok(rows)
 }
 
+pub async fn count_unread_card_decisions(
+    pool: &SqlitePool,
+    session_token: String,
+) -> Result<i64, AppError> {
+    let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+
+    let cnt = sqlx::query_scalar::<_, i64>(
+        r#"
+        SELECT COUNT(*)
+        FROM cards
+        WHERE created_by = ?1
+          AND status <> 'PENDING'
+          AND decision_seen = 0
+        "#,
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(cnt)
+}
+
+pub async fn mark_card_decisions_seen(
+    pool: &SqlitePool,
+    session_token: String,
+) -> Result<(), AppError> {
+    let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+
+    sqlx::query(
+        r#"
+        UPDATE cards
+        SET decision_seen = 1
+        WHERE created_by = ?1
+          AND status <> 'PENDING'
+          AND decision_seen = 0
+        "#,
+    )
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(())
+}
+
 pub async fn count_pending_cards(
     pool: &SqlitePool,
     sessionToken: String,
-) -> Result<i64, String> {
-    let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-    if role != "ADMIN" {
-        return Err("Forbidden: admin only.".into());
-    }
+) -> Result<i64, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &sessionToken, Permission::CardsReview).await?;
 
     let cnt = sqlx::query_scalar::<_, i64>(
-        r#"SELECT COUNT(*) FROM cards WHERE status = 'PENDING'"#,
+        r#"SELECT COUNT(*) FROM cards WHERE status = 'PENDING' AND archived_at IS NULL"#,
     )
     .fetch_one(pool)
     .await
@@ -178,99 +774,675 @@ pub async fn count_pending_cards(
 pub async fn list_all_cards_admin(
     pool: &SqlitePool,
     sessionToken: String,
-) -> Result<Vec<CardAdminItem>, String> {
-    let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-    if role != "ADMIN" {
-        return Err("Forbidden: admin only.".into());
-    }
+    query: CardListQuery,
+) -> Result<Page<CardAdminItem>, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &sessionToken, Permission::CardsEdit).await?;
 
-    let rows = sqlx::query_as::<_, CardAdminItem>(
+    let status = CardStatus::parse_filter(query.status.as_deref())?;
+    let category = Category::parse_filter(query.category.as_deref())?;
+    let order_by = sort_clause(query.sort.as_deref())?;
+    let (page, page_size) = page_bounds(query.page, query.page_size);
+    let total = count_cards(pool, &query, status, category).await?;
+
+    let mut qb = QueryBuilder::<Sqlite>::new(
         r#"
         SELECT
-            id,
-            category,
-            english,
-            latin,
-            image_path,
-            status
-        FROM cards
-        ORDER BY created_at DESC
+            c.id,
+            c.category,
+            c.english,
+            c.latin,
+            c.image_path,
+            c.region,
+            c.status,
+            c.created_by,
+            c.created_at
+        FROM cards c
         "#,
-    )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| format!("DB error: {e}"))?;
+    );
+    push_card_filters(&mut qb, &query, status, category);
+    qb.push(order_by)
+        .push(" LIMIT ")
+        .push_bind(page_size)
+        .push(" OFFSET ")
+        .push_bind((page - 1) * page_size);
 
-    Ok(rows)
+    let items = qb
+        .build_query_as::<CardAdminItem>()
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(Page {
+        items,
+        total,
+        page,
+        page_size,
+    })
 }
 
 pub async fn admin_update_card(
     pool: &SqlitePool,
+    assets: &AssetRoots,
     input: UpdateCardInput,
-) -> Result<(), String> {
-    let (_user_id, role) =
-        AuthService::require_session_user(pool, &input.sessionToken).await?;
-    if role != "ADMIN" {
-        return Err("Forbidden: admin only.".into());
-    }
+) -> Result<(), AppError> {
+    let (user_id, _role) =
+        AuthService::require_permission(pool, &input.sessionToken, Permission::CardsEdit).await?;
 
-    if input.english.trim().is_empty()
-        || input.latin.trim().is_empty()
-        || input.imagePath.trim().is_empty()
-    {
-        return Err("Please fill in all fields before saving.".into());
-    }
+    let card = check_card(
+        assets,
+        CardFields {
+            category: None,
+            english: &input.english,
+            latin: &input.latin,
+            image_path: Some(&input.imagePath),
+            region: input.region.as_ref(),
+        },
+    )
+    .map_err(AppError::validation)?;
 
-    let rows = sqlx::query(
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let old = card_revisions::snapshot(&mut tx, input.id)
+        .await?
+        .ok_or("Card not found.")?;
+
+    sqlx::query(
         r#"
         UPDATE cards
         SET english = ?1,
             latin = ?2,
-            image_path = ?3
-        WHERE id = ?4
+            image_path = ?3,
+            region = ?4,
+            image_hash = ?5
+        WHERE id = ?6
         "#,
     )
-    .bind(input.english.trim())
-    .bind(input.latin.trim())
-    .bind(input.imagePath.trim())
+    .bind(&card.english)
+    .bind(&card.latin)
+    .bind(&card.image_path)
+    .bind(&card.region)
+    .bind(duplicate_service::image_hash(assets, &card.image_path))
     .bind(input.id)
-    .execute(pool)
+    .execute(&mut *tx)
     .await
-    .map_err(|e| format!("Update failed: {e}"))?
-    .rows_affected();
+    .map_err(|e| format!("Update failed: {e}"))?;
 
-    if rows == 0 {
-        return Err("Card not found.".into());
-    }
+    let new = card_revisions::record(&mut tx, input.id, "UPDATE", user_id, Some(&old), None).await?;
+    audit_log::record(&mut tx, user_id, "CARD_UPDATE", "CARD", Some(input.id), json(&old), json(&new))
+        .await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Commit failed: {e}"))?;
 
     Ok(())
 }
 
+// "Delete" from the editor only archives the card: it leaves the games,
+// but attempts still point at it so stats keep working. See purge_card.
 pub async fn admin_delete_card(
     pool: &SqlitePool,
     sessionToken: String,
     id: i64,
-) -> Result<(), String> {
-    let (_user_id, role) = AuthService::require_session_user(pool, &sessionToken).await?;
-    if role != "ADMIN" {
-        return Err("Forbidden: admin only.".into());
+) -> Result<(), AppError> {
+    let (user_id, _role) =
+        AuthService::require_permission(pool, &sessionToken, Permission::CardsDelete).await?;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let old = card_revisions::snapshot(&mut tx, id)
+        .await?
+        .ok_or("Card not found.")?;
+
+    let rows = sqlx::query(
+        r#"UPDATE cards SET archived_at = ?1 WHERE id = ?2 AND archived_at IS NULL"#,
+    )
+    .bind(now_unix())
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Archive failed: {e}"))?
+    .rows_affected();
+
+    if rows == 0 {
+        return Err("Card is already archived.".into());
     }
 
-    let rows = sqlx::query(r#"DELETE FROM cards WHERE id = ?1"#)
-        .bind(id)
-        .execute(pool)
+    let new = card_revisions::record(&mut tx, id, "ARCHIVE", user_id, Some(&old), None).await?;
+    audit_log::record(&mut tx, user_id, "CARD_ARCHIVE", "CARD", Some(id), json(&old), json(&new)).await?;
+
+    tx.commit()
         .await
-        .map_err(|e| format!("Delete failed: {e}"))?
-        .rows_affected();
+        .map_err(|e| format!("Commit failed: {e}"))?;
+
+    Ok(())
+}
+
+pub async fn list_archived_cards(
+    pool: &SqlitePool,
+    session_token: String,
+) -> Result<Vec<ArchivedCard>, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsDelete).await?;
+
+    let rows = sqlx::query_as::<_, ArchivedCard>(
+        r#"
+        SELECT
+            c.id,
+            c.category,
+            c.english,
+            c.latin,
+            c.image_path,
+            c.status,
+            c.archived_at,
+            (SELECT COUNT(*) FROM card_attempts a WHERE a.card_id = c.id) AS attempt_count
+        FROM cards c
+        WHERE c.archived_at IS NOT NULL
+        ORDER BY c.archived_at DESC
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(rows)
+}
+
+pub async fn restore_card(
+    pool: &SqlitePool,
+    session_token: String,
+    card_id: i64,
+) -> Result<(), AppError> {
+    let (user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsDelete).await?;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let old = card_revisions::snapshot(&mut tx, card_id)
+        .await?
+        .ok_or("Card not found.")?;
+
+    let rows = sqlx::query(
+        r#"UPDATE cards SET archived_at = NULL WHERE id = ?1 AND archived_at IS NOT NULL"#,
+    )
+    .bind(card_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Restore failed: {e}"))?
+    .rows_affected();
 
     if rows == 0 {
-        return Err("Card not found.".into());
+        return Err("Card is not archived.".into());
     }
 
+    let new = card_revisions::record(&mut tx, card_id, "RESTORE", user_id, Some(&old), None).await?;
+    audit_log::record(&mut tx, user_id, "CARD_RESTORE", "CARD", Some(card_id), json(&old), json(&new))
+        .await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Commit failed: {e}"))?;
+
+    Ok(())
+}
+
+// Removes an archived card for good. Its attempts are either deleted too
+// ("DELETE") or kept without the card link ("ANONYMIZE"): they still count
+// in the player's totals and analytics, but no longer show up as a missed card.
+pub async fn purge_card(
+    pool: &SqlitePool,
+    session_token: String,
+    card_id: i64,
+    attempts: String,
+) -> Result<PurgeCardResult, AppError> {
+    let (user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsDelete).await?;
+
+    let attempts_sql = match attempts.as_str() {
+        "DELETE" => r#"DELETE FROM card_attempts WHERE card_id = ?1"#,
+        "ANONYMIZE" => r#"UPDATE card_attempts SET card_id = NULL WHERE card_id = ?1"#,
+        _ => {
+            return Err(AppError::new(
+                "PURGE_MODE_INVALID",
+                "Attempts must be either DELETE or ANONYMIZE.",
+            ))
+        }
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let archived = sqlx::query_scalar::<_, Option<i64>>(
+        r#"SELECT archived_at FROM cards WHERE id = ?1"#,
+    )
+    .bind(card_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?
+    .ok_or("Card not found.")?;
+
+    if archived.is_none() {
+        return Err(AppError::new(
+            "CARD_NOT_ARCHIVED",
+            "Archive the card before purging it.",
+        ));
+    }
+
+    let old = card_revisions::snapshot(&mut tx, card_id).await?;
+
+    let affected_attempts = sqlx::query(attempts_sql)
+        .bind(card_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Purge attempts failed: {e}"))?
+        .rows_affected();
+
+    sqlx::query(r#"DELETE FROM cards WHERE id = ?1"#)
+        .bind(card_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("Purge failed: {e}"))?;
+
+    let note = format!("Purged, attempts: {attempts} ({affected_attempts})");
+    card_revisions::record(&mut tx, card_id, "PURGE", user_id, old.as_ref(), Some(&note)).await?;
+    audit_log::record(
+        &mut tx,
+        user_id,
+        "CARD_PURGE",
+        "CARD",
+        Some(card_id),
+        json(&old),
+        json(&serde_json::json!({ "attempts": attempts, "affectedAttempts": affected_attempts })),
+    )
+    .await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Commit failed: {e}"))?;
+
+    Ok(PurgeCardResult {
+        card_id,
+        attempts,
+        affected_attempts,
+    })
+}
+
+pub async fn list_cards_missing_images(
+    pool: &SqlitePool,
+    assets: &AssetRoots,
+    session_token: String,
+) -> Result<Vec<MissingImageCard>, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsEdit).await?;
+
+    let rows = sqlx::query_as::<_, CardAdminItem>(
+        r#"
+        SELECT
+            id,
+            category,
+            english,
+            latin,
+            image_path,
+            region,
+            status,
+            created_by,
+            created_at
+        FROM cards
+        ORDER BY id ASC
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    let missing = rows
+        .into_iter()
+        .filter_map(|c| {
+            let problem = assets.resolve(&c.image_path).err()?;
+            Some(MissingImageCard {
+                id: c.id,
+                category: c.category,
+                english: c.english,
+                latin: c.latin,
+                image_path: c.image_path,
+                status: c.status,
+                problem: problem.code().to_string(),
+            })
+        })
+        .collect();
+
+    Ok(missing)
+}
+
+pub async fn list_card_history(
+    pool: &SqlitePool,
+    session_token: String,
+    card_id: i64,
+) -> Result<Vec<CardRevision>, AppError> {
+    let (_user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsEdit).await?;
+
+    let rows = sqlx::query_as::<_, CardRevision>(
+        r#"
+        SELECT
+            r.id,
+            r.card_id,
+            r.action,
+            r.editor_id,
+            u.username AS editor_username,
+            r.created_at,
+            r.old_values,
+            r.new_values,
+            r.note
+        FROM card_revisions r
+        LEFT JOIN users u ON u.id = r.editor_id
+        WHERE r.card_id = ?1
+        ORDER BY r.id DESC
+        "#,
+    )
+    .bind(card_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    Ok(rows)
+}
+
+// Puts the card content back to how it looked right after `revision_id`.
+// Moderation status is left alone; the revert itself becomes a new revision.
+pub async fn revert_card(
+    pool: &SqlitePool,
+    assets: &AssetRoots,
+    session_token: String,
+    revision_id: i64,
+) -> Result<(), AppError> {
+    let (user_id, _role) =
+        AuthService::require_permission(pool, &session_token, Permission::CardsEdit).await?;
+
+    let revision = sqlx::query_as::<_, (i64, Option<Json<CardSnapshot>>)>(
+        r#"SELECT card_id, new_values FROM card_revisions WHERE id = ?1"#,
+    )
+    .bind(revision_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    let Some((card_id, target)) = revision else {
+        return Err(AppError::new("REVISION_NOT_FOUND", "Revision not found."));
+    };
+    let Some(Json(target)) = target else {
+        return Err(AppError::new(
+            "REVISION_EMPTY",
+            "This revision has no card values to revert to.",
+        ));
+    };
+
+    // same rules as an edit: the old image may have been removed since, and
+    // revisions written before validation existed may not pass at all
+    let card = check_card(
+        assets,
+        CardFields {
+            category: Some(&target.category),
+            english: &target.english,
+            latin: &target.latin,
+            image_path: Some(&target.image_path),
+            region: target.region.as_ref().map(|r| &r.0),
+        },
+    )
+    .map_err(AppError::validation)?;
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+    let old = card_revisions::snapshot(&mut tx, card_id)
+        .await?
+        .ok_or("Card not found.")?;
+
+    sqlx::query(
+        r#"
+        UPDATE cards
+        SET category = ?1,
+            english = ?2,
+            latin = ?3,
+            image_path = ?4,
+            region = ?5,
+            synonyms = ?6,
+            description = ?7,
+            image_hash = ?8
+        WHERE id = ?9
+        "#,
+    )
+    .bind(card.category.map(|c| c.as_str()).unwrap_or(&target.category))
+    .bind(&card.english)
+    .bind(&card.latin)
+    .bind(&card.image_path)
+    .bind(&card.region)
+    .bind(target.synonyms)
+    .bind(target.description)
+    .bind(duplicate_service::image_hash(assets, &card.image_path))
+    .bind(card_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| format!("Revert failed: {e}"))?;
+
+    let note = format!("Reverted to revision {revision_id}");
+    let new = card_revisions::record(&mut tx, card_id, "REVERT", user_id, Some(&old), Some(&note)).await?;
+    audit_log::record(&mut tx, user_id, "CARD_REVERT", "CARD", Some(card_id), json(&old), json(&new))
+        .await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("Commit failed: {e}"))?;
+
     Ok(())
 }
 
+}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::{login_as, temp_pool};
 
+    async fn pending_card(pool: &SqlitePool, english: &str) -> i64 {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at)
+            VALUES ('BONES', ?1, ?1, '/cards/bones/test.png', 'PENDING', 0)
+            RETURNING id
+            "#,
+        )
+        .bind(english)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
 
+    async fn status(pool: &SqlitePool, id: i64) -> String {
+        sqlx::query_scalar("SELECT status FROM cards WHERE id = ?1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn cards_whose_image_was_deleted_are_listed() {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+
+        let image = dir.path().join("cards/bones/test.png");
+        std::fs::create_dir_all(image.parent().unwrap()).unwrap();
+        std::fs::write(&image, b"png").unwrap();
+        let assets = AssetRoots::new(vec![dir.path().to_path_buf()]);
+        let card = pending_card(&pool, "Femur").await;
+
+        let missing = CardService::list_cards_missing_images(&pool, &assets, token.clone())
+            .await
+            .unwrap();
+        assert!(missing.is_empty());
+
+        std::fs::remove_file(&image).unwrap();
+        let missing = CardService::list_cards_missing_images(&pool, &assets, token)
+            .await
+            .unwrap();
+        let found: Vec<_> = missing.iter().map(|c| (c.id, c.problem.as_str())).collect();
+        assert_eq!(found, vec![(card, "IMAGE_NOT_FOUND")]);
+    }
+
+    #[tokio::test]
+    async fn moderator_cannot_publish_cards_directly() {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "moderator", "MODERATOR").await;
+        let assets = AssetRoots::new(vec![dir.path().to_path_buf()]);
+
+        let input = CreateCardInput {
+            sessionToken: token,
+            category: "BONES".into(),
+            english: "Thigh bone".into(),
+            latin: "Femur".into(),
+            imagePath: "/cards/bones/femur.png".into(),
+            region: None,
+        };
+        let err = CardService::add_card_admin(&pool, &assets, input).await.err().unwrap();
+        assert_eq!(err.code, "FORBIDDEN");
+
+        let cards: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM cards")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(cards, 0);
+    }
+
+    #[tokio::test]
+    async fn unknown_status_or_category_filters_are_refused() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        pending_card(&pool, "Femur").await;
+
+        let query = |status: &str, category: &str| CardListQuery {
+            status: Some(status.into()),
+            category: Some(category.into()),
+            ..Default::default()
+        };
+
+        let page = CardService::list_all_cards_admin(&pool, token.clone(), query(" pending", "bones"))
+            .await
+            .unwrap();
+        assert_eq!(page.total, 1);
+
+        assert!(CardService::list_all_cards_admin(&pool, token.clone(), query("PENDNG", ""))
+            .await
+            .is_err());
+        assert!(CardService::list_all_cards_admin(&pool, token.clone(), query("", "bone"))
+            .await
+            .is_err());
+        assert!(CardService::list_pending_cards(&pool, token, query("", "bone")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn anonymized_purge_keeps_attempts_without_a_card() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        let card = pending_card(&pool, "Femur").await;
+
+        sqlx::query("UPDATE cards SET archived_at = 1 WHERE id = ?1")
+            .bind(card)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO card_attempts (user_id, card_id, is_won) VALUES (1, ?1, 0)")
+            .bind(card)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = CardService::purge_card(&pool, token, card, "ANONYMIZE".into()).await.unwrap();
+        assert_eq!(result.affected_attempts, 1);
+
+        let card_ids: Vec<Option<i64>> = sqlx::query_scalar("SELECT card_id FROM card_attempts")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(card_ids, vec![None]);
+    }
+
+    #[tokio::test]
+    async fn batch_review_reports_missing_ids_and_keeps_the_rest() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        let a = pending_card(&pool, "Femur").await;
+        let b = pending_card(&pool, "Tibia").await;
+
+        let results = CardService::approve_cards(&pool, token, vec![a, 9999, b], None)
+            .await
+            .unwrap();
+
+        let ok: Vec<_> = results.iter().map(|r| (r.card_id, r.ok)).collect();
+        assert_eq!(ok, vec![(a, true), (9999, false), (b, true)]);
+        assert_eq!(status(&pool, a).await, "APPROVED");
+        assert_eq!(status(&pool, b).await, "APPROVED");
+    }
+
+    #[tokio::test]
+    async fn only_pending_cards_can_be_reviewed() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        let a = pending_card(&pool, "Femur").await;
+
+        CardService::approve_card(&pool, token.clone(), a, None).await.unwrap();
+        let err = CardService::reject_card(&pool, token.clone(), a, None).await.err().unwrap();
+        assert_eq!(err.code, "CARD_NOT_PENDING");
+        assert_eq!(status(&pool, a).await, "APPROVED");
+
+        let results = CardService::reject_cards(&pool, token, vec![a], None).await.unwrap();
+        assert!(!results[0].ok);
+        assert_eq!(status(&pool, a).await, "APPROVED");
+    }
+
+    #[tokio::test]
+    async fn revert_refuses_a_revision_that_fails_validation() {
+        let (_dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+        let card = pending_card(&pool, "Femur").await;
+        let assets = AssetRoots::new(vec![]);
+
+        let bad = r#"{"category":"BONES","english":"","latin":"Femur","imagePath":"/cards/../secret.png","region":null,"synonyms":null,"description":null,"status":"PENDING"}"#;
+        let revision: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO card_revisions (card_id, action, editor_id, created_at, new_values)
+            VALUES (?1, 'UPDATE', 1, 0, ?2)
+            RETURNING id
+            "#,
+        )
+        .bind(card)
+        .bind(bad)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let err = CardService::revert_card(&pool, &assets, token, revision).await.err().unwrap();
+        assert_eq!(err.code, "VALIDATION_FAILED");
+
+        let english: String = sqlx::query_scalar("SELECT english FROM cards WHERE id = ?1")
+            .bind(card)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(english, "Femur");
+    }
 }
@@ -0,0 +1,171 @@
+use hangman_core::difficulty::{refine_with_history, term_difficulty, DifficultyTier};
+use sqlx::SqlitePool;
+use time::OffsetDateTime;
+
+use crate::db;
+use crate::domain::card::Card;
+use crate::domain::category::Category;
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+// Players may get either language, so both terms count equally
+pub fn base_difficulty(english: &str, latin: &str) -> f64 {
+    (term_difficulty(english) + term_difficulty(latin)) / 2.0
+}
+
+// Stored score, or the term-only score for cards not refreshed yet
+pub fn card_tier(card: &Card) -> DifficultyTier {
+    let score = card
+        .difficulty
+        .unwrap_or_else(|| base_difficulty(&card.english, &card.latin));
+    DifficultyTier::from_score(score)
+}
+
+// Playable cards of a category, only those in `tier` when one is asked for
+pub async fn deck_for(
+    pool: &SqlitePool,
+    category: Category,
+    tier: Option<DifficultyTier>,
+) -> Result<Vec<Card>, AppError> {
+    let mut deck = db::cards::get_cards_by_category(pool, category).await?;
+
+    if let Some(tier) = tier {
+        deck.retain(|card| card_tier(card) == tier);
+    }
+
+    Ok(deck)
+}
+
+#[derive(sqlx::FromRow)]
+struct CardPlays {
+    id: i64,
+    english: String,
+    latin: String,
+    attempts: i64,
+    wins: i64,
+}
+
+pub struct DifficultyService;
+
+impl DifficultyService {
+    // Recomputes the score of every live card; returns how many were updated
+    pub async fn refresh_all(pool: &SqlitePool) -> Result<usize, AppError> {
+        let rows = sqlx::query_as::<_, CardPlays>(
+            r#"
+            SELECT
+                c.id,
+                c.english,
+                c.latin,
+                COUNT(a.id) AS attempts,
+                COALESCE(SUM(a.is_won), 0) AS wins
+            FROM cards c
+            LEFT JOIN card_attempts a ON a.card_id = c.id
+            WHERE c.archived_at IS NULL
+            GROUP BY c.id
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        let now = now_unix();
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        for row in &rows {
+            let score = refine_with_history(base_difficulty(&row.english, &row.latin), row.attempts, row.wins);
+
+            sqlx::query("UPDATE cards SET difficulty = ?1, difficulty_updated_at = ?2 WHERE id = ?3")
+                .bind(score)
+                .bind(now)
+                .bind(row.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Difficulty update failed: {e}"))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Difficulty commit failed: {e}"))?;
+
+        Ok(rows.len())
+    }
+
+    // Manual refresh for editors who don't want to wait for the timer
+    pub async fn refresh_card_difficulty(pool: &SqlitePool, session_token: String) -> Result<usize, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::CardsEdit).await?;
+
+        Self::refresh_all(pool).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::temp_pool;
+
+    async fn card(pool: &SqlitePool, english: &str, difficulty: Option<f64>) -> i64 {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at, difficulty)
+            VALUES ('BONES', ?1, ?1, '/cards/bones/test.png', 'APPROVED', 0, ?2)
+            RETURNING id
+            "#,
+        )
+        .bind(english)
+        .bind(difficulty)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn refresh_stores_the_score_refined_by_history() {
+        let (_dir, pool) = temp_pool().await;
+        let femur = card(&pool, "Femur", None).await;
+        for won in [true, false, false, false] {
+            sqlx::query("INSERT INTO card_attempts (user_id, card_id, is_won) VALUES (1, ?1, ?2)")
+                .bind(femur)
+                .bind(won)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(DifficultyService::refresh_all(&pool).await.unwrap(), 1);
+
+        let (difficulty, updated_at): (Option<f64>, Option<i64>) =
+            sqlx::query_as("SELECT difficulty, difficulty_updated_at FROM cards WHERE id = ?1")
+                .bind(femur)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        let expected = refine_with_history(base_difficulty("Femur", "Femur"), 4, 1);
+        assert_eq!(difficulty, Some(expected));
+        assert!(updated_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn deck_keeps_only_the_requested_tier() {
+        let (_dir, pool) = temp_pool().await;
+        card(&pool, "Femur", Some(0.1)).await;
+        card(&pool, "Tibia", Some(0.5)).await;
+        let hard = card(&pool, "Fibula", Some(0.9)).await;
+
+        let deck = deck_for(&pool, Category::Bones, Some(DifficultyTier::Hard)).await.unwrap();
+        let ids: Vec<_> = deck.iter().map(|c| c.id).collect();
+        assert_eq!(ids, vec![hard]);
+
+        let deck = deck_for(&pool, Category::Bones, None).await.unwrap();
+        assert_eq!(deck.len(), 3);
+    }
+}
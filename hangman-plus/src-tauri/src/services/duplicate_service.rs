@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+
+use hangman_core::text::{normalize_term, term_similarity, LIKELY_DUPLICATE};
+use sqlx::{FromRow, SqlitePool};
+
+use crate::domain::category::Category;
+use crate::domain::dto::DuplicateMatch;
+use crate::errors::AppError;
+use crate::utils::assets::AssetRoots;
+use crate::utils::security::sha256_hex;
+
+// Only the closest few are worth showing
+const MAX_MATCHES: usize = 5;
+
+#[derive(FromRow)]
+struct Candidate {
+    id: i64,
+    english: String,
+    latin: String,
+    status: String,
+    has_region: bool,
+    image_hash: Option<String>,
+}
+
+// The card being added or edited
+pub struct NewCardTerms<'a> {
+    pub category: Category,
+    pub english: &'a str,
+    pub latin: &'a str,
+    pub image_hash: Option<&'a str>,
+    pub has_region: bool,
+}
+
+// Content hash of a card image, stored on the card as `image_hash`
+pub fn image_hash(assets: &AssetRoots, image_path: &str) -> Option<String> {
+    let path = assets.resolve(image_path).ok()?;
+    let bytes = std::fs::read(path).ok()?;
+    Some(sha256_hex(&bytes))
+}
+
+// Key the importers match terms on: same category, same term after normalize_term
+pub fn term_key(category: &str, term: &str) -> (String, String) {
+    (category.to_string(), normalize_term(term))
+}
+
+pub struct DuplicateService;
+
+impl DuplicateService {
+    // image_hash is a cache of the image file content. Card writes fill it for
+    // their own image; this fills the rest (older rows, imports) once at startup.
+    pub async fn backfill_image_hashes(
+        pool: &SqlitePool,
+        assets: &AssetRoots,
+    ) -> Result<(), AppError> {
+        let rows = sqlx::query_as::<_, (i64, String)>(
+            r#"SELECT id, image_path FROM cards WHERE image_hash IS NULL"#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        for (id, image_path) in rows {
+            // missing files stay NULL until the next start
+            let Some(hash) = image_hash(assets, &image_path) else {
+                continue;
+            };
+
+            sqlx::query(r#"UPDATE cards SET image_hash = ?1 WHERE id = ?2"#)
+                .bind(hash)
+                .bind(id)
+                .execute(pool)
+                .await
+                .map_err(|e| format!("DB error: {e}"))?;
+        }
+
+        Ok(())
+    }
+
+    // Term key -> card id for both languages of every live card (same cards as
+    // find_duplicates), so an import can check all of its rows without a query per row.
+    pub async fn existing_terms(pool: &SqlitePool) -> Result<HashMap<(String, String), i64>, AppError> {
+        let rows = sqlx::query_as::<_, (i64, String, String, String)>(
+            r#"
+            SELECT id, category, english, latin
+            FROM cards
+            WHERE archived_at IS NULL
+              AND status <> 'REJECTED'
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        let mut terms = HashMap::new();
+        for (id, category, english, latin) in rows {
+            terms.entry(term_key(&category, &english)).or_insert(id);
+            terms.entry(term_key(&category, &latin)).or_insert(id);
+        }
+
+        Ok(terms)
+    }
+
+    // Compares both terms against both languages of every live card in the same
+    // category, plus the image content for plain cards (cards with a region share
+    // one diagram on purpose). Archived and rejected cards don't count.
+    pub async fn find_duplicates(
+        pool: &SqlitePool,
+        card: NewCardTerms<'_>,
+        exclude_id: Option<i64>,
+    ) -> Result<Vec<DuplicateMatch>, AppError> {
+        let image_hash = if card.has_region { None } else { card.image_hash };
+
+        let candidates = sqlx::query_as::<_, Candidate>(
+            r#"
+            SELECT
+                id,
+                english,
+                latin,
+                status,
+                region IS NOT NULL AS has_region,
+                image_hash
+            FROM cards
+            WHERE archived_at IS NULL
+              AND status <> 'REJECTED'
+              AND category = ?1
+              AND id <> ?2
+            "#,
+        )
+        .bind(card.category.as_str())
+        .bind(exclude_id.unwrap_or(0))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        let new_terms = [card.english, card.latin];
+
+        let mut matches: Vec<DuplicateMatch> = candidates
+            .into_iter()
+            .filter_map(|c| {
+                let old_terms = [c.english.as_str(), c.latin.as_str()];
+
+                let same_term = new_terms.iter().any(|n| {
+                    let n = normalize_term(n);
+                    !n.is_empty() && old_terms.iter().any(|o| normalize_term(o) == n)
+                });
+
+                let score = new_terms
+                    .iter()
+                    .flat_map(|n| old_terms.iter().map(move |o| term_similarity(n, o)))
+                    .fold(0.0, f64::max);
+
+                let same_image = !c.has_region
+                    && image_hash.is_some()
+                    && c.image_hash.as_deref() == image_hash;
+
+                let (reason, score) = if same_term {
+                    ("SAME_TERM", 1.0)
+                } else if score >= LIKELY_DUPLICATE {
+                    ("SIMILAR_TERM", score)
+                } else if same_image {
+                    ("SAME_IMAGE", 1.0)
+                } else {
+                    return None;
+                };
+
+                Some(DuplicateMatch {
+                    card_id: c.id,
+                    english: c.english,
+                    latin: c.latin,
+                    status: c.status,
+                    reason: reason.to_string(),
+                    score: (score * 100.0).round() / 100.0,
+                })
+            })
+            .collect();
+
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(MAX_MATCHES);
+
+        Ok(matches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::temp_pool;
+
+    async fn card(pool: &SqlitePool, category: &str, english: &str, latin: &str, image_hash: &str) -> i64 {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, image_hash, status, created_at)
+            VALUES (?1, ?2, ?3, '/cards/test.png', ?4, 'APPROVED', 0)
+            RETURNING id
+            "#,
+        )
+        .bind(category)
+        .bind(english)
+        .bind(latin)
+        .bind(image_hash)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    fn terms<'a>(category: Category, english: &'a str, latin: &'a str, image_hash: &'a str) -> NewCardTerms<'a> {
+        NewCardTerms {
+            category,
+            english,
+            latin,
+            image_hash: Some(image_hash),
+            has_region: false,
+        }
+    }
+
+    async fn reasons(pool: &SqlitePool, card: NewCardTerms<'_>, exclude_id: Option<i64>) -> Vec<(i64, String)> {
+        DuplicateService::find_duplicates(pool, card, exclude_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|m| (m.card_id, m.reason))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn finds_same_term_similar_term_and_same_image_in_the_category() {
+        let (_dir, pool) = temp_pool().await;
+        let femur = card(&pool, "BONES", "Thigh bone", "Femur", "aaa").await;
+        let radius = card(&pool, "BONES", "Forearm bone", "Radius", "bbb").await;
+        card(&pool, "ORGANS", "Heart", "Cor", "ccc").await;
+
+        let found = reasons(&pool, terms(Category::Bones, "thigh  bone", "Os femoris", "xxx"), None).await;
+        assert_eq!(found, vec![(femur, "SAME_TERM".to_string())]);
+
+        let found = reasons(&pool, terms(Category::Bones, "Forearm bones", "Radii", "xxx"), None).await;
+        assert_eq!(found, vec![(radius, "SIMILAR_TERM".to_string())]);
+
+        let found = reasons(&pool, terms(Category::Bones, "Kneecap", "Patella", "bbb"), None).await;
+        assert_eq!(found, vec![(radius, "SAME_IMAGE".to_string())]);
+
+        // another category is not a duplicate
+        let found = reasons(&pool, terms(Category::Bones, "Heart", "Cor", "ccc"), None).await;
+        assert!(found.is_empty());
+
+        // a card being edited doesn't match itself
+        let found = reasons(&pool, terms(Category::Bones, "Thigh bone", "Femur", "aaa"), Some(femur)).await;
+        assert!(found.is_empty());
+    }
+}
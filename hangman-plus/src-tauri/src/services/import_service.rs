@@ -0,0 +1,476 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use time::OffsetDateTime;
+
+use crate::db::audit_log::{self, json};
+use crate::db::card_revisions;
+use crate::domain::category::Category;
+use crate::domain::dto::{ImportReport, ImportRowResult};
+use crate::domain::region::Region;
+use crate::domain::role::Permission;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+use crate::domain::validation::CardFields;
+use crate::services::card_service::check_card;
+use crate::services::duplicate_service::{self, term_key, DuplicateService};
+use crate::utils::assets::AssetRoots;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+// CSV columns: category,english,latin,image[,region,synonyms,description]
+// `region` is the same JSON the single add takes, `synonyms` are separated by ';'
+#[derive(Debug, Deserialize)]
+struct CsvRow {
+    category: String,
+    english: String,
+    latin: String,
+    #[serde(alias = "image_path", alias = "imagePath")]
+    image: String,
+    #[serde(default)]
+    region: Option<String>,
+    #[serde(default)]
+    synonyms: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+// JSON: an array of objects with the same keys, `region` as an object
+#[derive(Debug, Deserialize)]
+struct JsonRow {
+    category: String,
+    english: String,
+    latin: String,
+    #[serde(alias = "image_path", alias = "imagePath")]
+    image: String,
+    #[serde(default)]
+    region: Option<Region>,
+    #[serde(default)]
+    synonyms: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[derive(Debug)]
+struct ImportRow {
+    category: String,
+    english: String,
+    latin: String,
+    image: String,
+    region: Option<Region>,
+    synonyms: Option<String>,
+    description: Option<String>,
+}
+
+// Row that passed validation, ready for INSERT
+struct ValidRow {
+    category: Category,
+    english: String,
+    latin: String,
+    image_path: String,
+    region: Option<String>,
+    synonyms: Option<String>,
+    description: Option<String>,
+}
+
+fn non_empty(v: Option<String>) -> Option<String> {
+    v.map(|s| s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+fn parse_csv(text: &str) -> Result<Vec<Result<ImportRow, String>>, AppError> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(text.as_bytes());
+
+    let rows = reader
+        .deserialize::<CsvRow>()
+        .map(|rec| {
+            let r = rec.map_err(|e| format!("Bad CSV row: {e}"))?;
+
+            let region = match non_empty(r.region) {
+                Some(json) => Some(
+                    serde_json::from_str::<Region>(&json)
+                        .map_err(|e| format!("Bad region JSON: {e}"))?,
+                ),
+                None => None,
+            };
+
+            Ok(ImportRow {
+                category: r.category,
+                english: r.english,
+                latin: r.latin,
+                image: r.image,
+                region,
+                synonyms: r.synonyms,
+                description: r.description,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn parse_json(text: &str) -> Result<Vec<Result<ImportRow, String>>, AppError> {
+    let values: Vec<serde_json::Value> =
+        serde_json::from_str(text).map_err(|e| format!("File must be a JSON array: {e}"))?;
+
+    let rows = values
+        .into_iter()
+        .map(|v| {
+            let r = serde_json::from_value::<JsonRow>(v).map_err(|e| format!("Bad row: {e}"))?;
+
+            Ok(ImportRow {
+                category: r.category,
+                english: r.english,
+                latin: r.latin,
+                image: r.image,
+                region: r.region,
+                synonyms: r.synonyms,
+                description: r.description,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn validate_row(assets: &AssetRoots, row: ImportRow) -> Result<ValidRow, Vec<String>> {
+    let card = check_card(
+        assets,
+        CardFields {
+            category: Some(&row.category),
+            english: &row.english,
+            latin: &row.latin,
+            image_path: Some(&row.image),
+            region: row.region.as_ref(),
+        },
+    )
+    .map_err(|errors| errors.into_iter().map(|e| e.message).collect::<Vec<_>>())?;
+
+    let Some(category) = card.category else {
+        return Err(vec!["Category is required.".to_string()]);
+    };
+
+    Ok(ValidRow {
+        category,
+        english: card.english,
+        latin: card.latin,
+        image_path: card.image_path,
+        region: card.region,
+        synonyms: non_empty(row.synonyms),
+        description: non_empty(row.description),
+    })
+}
+
+pub struct ImportService;
+
+impl ImportService {
+    pub async fn import_cards(
+        pool: &SqlitePool,
+        assets: &AssetRoots,
+        session_token: String,
+        file_path: String,
+        dry_run: bool,
+    ) -> Result<ImportReport, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::CardsImport).await?;
+
+        let path = Path::new(file_path.trim());
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| AppError::new("IMPORT_FILE_UNREADABLE", format!("Cannot read file: {e}")))?;
+
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase());
+
+        let parsed = match ext.as_deref() {
+            Some("csv") => parse_csv(&text),
+            Some("json") => parse_json(&text),
+            _ => {
+                return Err(AppError::new(
+                    "IMPORT_FORMAT_UNSUPPORTED",
+                    "Only .csv and .json files can be imported.",
+                ))
+            }
+        }
+        .map_err(|msg| AppError::new("IMPORT_FILE_INVALID", msg))?;
+
+        let mut results = Vec::with_capacity(parsed.len());
+        let mut valid = Vec::new();
+
+        // cards already in the DB, and term key -> row number for duplicates inside the file
+        let existing = DuplicateService::existing_terms(pool).await?;
+        let mut seen: HashMap<(String, String), usize> = HashMap::new();
+
+        for (i, parsed_row) in parsed.into_iter().enumerate() {
+            let row_no = i + 1;
+
+            let row = match parsed_row {
+                Ok(r) => r,
+                Err(msg) => {
+                    results.push(ImportRowResult {
+                        row: row_no,
+                        english: String::new(),
+                        status: "ERROR".into(),
+                        errors: vec![msg],
+                        duplicate_of: None,
+                    });
+                    continue;
+                }
+            };
+
+            let english = row.english.trim().to_string();
+
+            let row = match validate_row(assets, row) {
+                Ok(r) => r,
+                Err(errors) => {
+                    results.push(ImportRowResult {
+                        row: row_no,
+                        english,
+                        status: "ERROR".into(),
+                        errors,
+                        duplicate_of: None,
+                    });
+                    continue;
+                }
+            };
+
+            let keys = [
+                term_key(row.category.as_str(), &row.english),
+                term_key(row.category.as_str(), &row.latin),
+            ];
+
+            if let Some(first) = keys.iter().find_map(|k| seen.get(k)) {
+                results.push(ImportRowResult {
+                    row: row_no,
+                    english,
+                    status: "DUPLICATE".into(),
+                    errors: vec![format!("Same term as row {first}.")],
+                    duplicate_of: None,
+                });
+                continue;
+            }
+
+            if let Some(&existing_id) = keys.iter().find_map(|k| existing.get(k)) {
+                results.push(ImportRowResult {
+                    row: row_no,
+                    english,
+                    status: "DUPLICATE".into(),
+                    errors: vec![format!("Card already exists (id {existing_id}).")],
+                    duplicate_of: Some(existing_id),
+                });
+                continue;
+            }
+
+            for k in keys {
+                seen.entry(k).or_insert(row_no);
+            }
+
+            results.push(ImportRowResult {
+                row: row_no,
+                english,
+                status: "OK".into(),
+                errors: Vec::new(),
+                duplicate_of: None,
+            });
+            valid.push(row);
+        }
+
+        let error_rows = results.iter().filter(|r| r.status == "ERROR").count();
+        let duplicate_rows = results.iter().filter(|r| r.status == "DUPLICATE").count();
+
+        let mut report = ImportReport {
+            dry_run,
+            total_rows: results.len(),
+            valid_rows: valid.len(),
+            error_rows,
+            duplicate_rows,
+            imported: 0,
+            rows: results,
+        };
+
+        // all or nothing: any bad row blocks the whole import
+        if dry_run || error_rows > 0 || duplicate_rows > 0 || valid.is_empty() {
+            return Ok(report);
+        }
+
+        let now = now_unix();
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let mut card_ids = Vec::with_capacity(valid.len());
+
+        for row in &valid {
+            let id = sqlx::query_scalar::<_, i64>(
+                r#"
+                INSERT INTO cards (category, english, latin, image_path, region, image_hash, synonyms, description, status, created_by, created_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, 'APPROVED', ?9, ?10)
+                RETURNING id
+                "#,
+            )
+            .bind(row.category.as_str())
+            .bind(&row.english)
+            .bind(&row.latin)
+            .bind(&row.image_path)
+            .bind(&row.region)
+            .bind(duplicate_service::image_hash(assets, &row.image_path))
+            .bind(&row.synonyms)
+            .bind(&row.description)
+            .bind(user_id)
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| format!("Import insert failed: {e}"))?;
+
+            card_revisions::record(&mut tx, id, "CREATE", user_id, None, Some("Bulk import"))
+                .await?;
+            card_ids.push(id);
+        }
+
+        let summary = serde_json::json!({ "file": path.display().to_string(), "cardIds": card_ids });
+        audit_log::record(&mut tx, user_id, "CARD_IMPORT", "CARDS", None, None, json(&summary)).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Import commit failed: {e}"))?;
+
+        report.imported = valid.len();
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::{login_as, temp_pool};
+
+    async fn card_count(pool: &SqlitePool) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM cards")
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_and_commit_imports() {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+
+        std::fs::create_dir_all(dir.path().join("public/cards/bones")).unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/femur.png"), b"femur").unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/tibia.png"), b"tibia").unwrap();
+        let assets = AssetRoots::new(vec![dir.path().join("public")]);
+
+        let csv = dir.path().join("cards.csv");
+        std::fs::write(
+            &csv,
+            "category,english,latin,image\n\
+             BONES,Thigh bone,Femur,/cards/bones/femur.png\n\
+             BONES,Shin bone,Tibia,/cards/bones/tibia.png\n",
+        )
+        .unwrap();
+        let file = csv.display().to_string();
+
+        let report = ImportService::import_cards(&pool, &assets, token.clone(), file.clone(), true)
+            .await
+            .unwrap();
+        assert!(report.dry_run);
+        assert_eq!((report.valid_rows, report.imported), (2, 0));
+        assert_eq!(card_count(&pool).await, 0);
+
+        let report = ImportService::import_cards(&pool, &assets, token.clone(), file.clone(), false)
+            .await
+            .unwrap();
+        assert!(!report.dry_run);
+        assert_eq!(report.imported, 2);
+        assert_eq!(card_count(&pool).await, 2);
+
+        // imported cards take part in the same-image check right away
+        let hashed: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM cards WHERE image_hash IS NOT NULL")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(hashed, 2);
+
+        // the same file again is all duplicates, and nothing is written
+        let report = ImportService::import_cards(&pool, &assets, token, file, false)
+            .await
+            .unwrap();
+        assert_eq!((report.duplicate_rows, report.imported), (2, 0));
+        assert_eq!(card_count(&pool).await, 2);
+    }
+
+    #[tokio::test]
+    async fn terms_are_compared_after_normalizing() {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+
+        std::fs::create_dir_all(dir.path().join("public/cards/bones")).unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/femur.png"), b"femur").unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/tibia.png"), b"tibia").unwrap();
+        let assets = AssetRoots::new(vec![dir.path().join("public")]);
+
+        let femur: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at)
+            VALUES ('BONES', 'Thigh bone', 'Femur', '/cards/bones/femur.png', 'APPROVED', 0)
+            RETURNING id
+            "#,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let csv = dir.path().join("cards.csv");
+        std::fs::write(
+            &csv,
+            "category,english,latin,image
+             BONES,Thigh  bone.,Os femoris,/cards/bones/femur.png
+             BONES,Shin bone,Tibia,/cards/bones/tibia.png
+             BONES,shin-bone,Os tibiae,/cards/bones/tibia.png
+",
+        )
+        .unwrap();
+
+        let report = ImportService::import_cards(&pool, &assets, token, csv.display().to_string(), true)
+            .await
+            .unwrap();
+        let statuses: Vec<_> = report.rows.iter().map(|r| r.status.as_str()).collect();
+        assert_eq!(statuses, vec!["DUPLICATE", "OK", "DUPLICATE"]);
+        assert_eq!(report.rows[0].duplicate_of, Some(femur));
+    }
+
+    #[tokio::test]
+    async fn one_bad_row_blocks_the_whole_import() {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+
+        std::fs::create_dir_all(dir.path().join("public/cards/bones")).unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/femur.png"), b"femur").unwrap();
+        let assets = AssetRoots::new(vec![dir.path().join("public")]);
+
+        let json = dir.path().join("cards.json");
+        std::fs::write(
+            &json,
+            r#"[
+                {"category":"BONES","english":"Thigh bone","latin":"Femur","image":"/cards/bones/femur.png"},
+                {"category":"BONES","english":"","latin":"Tibia","image":"/cards/../cards.json"}
+            ]"#,
+        )
+        .unwrap();
+
+        let report = ImportService::import_cards(&pool, &assets, token, json.display().to_string(), false)
+            .await
+            .unwrap();
+        assert_eq!((report.valid_rows, report.error_rows, report.imported), (1, 1, 0));
+        assert_eq!(card_count(&pool).await, 0);
+    }
+}
@@ -1,4 +1,14 @@
+pub mod analytics_service;
+pub mod audit_service;
 pub mod auth_service;
+pub mod bot_service;
 pub mod card_service;
+pub mod difficulty_service;
+pub mod duplicate_service;
+pub mod import_service;
+pub mod pack_service;
 pub mod profile_service;
-
+pub mod rating_service;
+pub mod search_service;
+pub mod session_service;
+pub mod user_service;
@@ -0,0 +1,1061 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use sqlx::{types::Json, SqliteConnection, SqlitePool};
+use time::OffsetDateTime;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::db::audit_log::{self, json};
+use crate::db::card_revisions;
+use crate::domain::category::Category;
+use crate::domain::dto::{
+    PackExportResponse, PackImportReport, PackImportRow, PackInspectResponse, SigningKeyResponse,
+    TrustedPublisher,
+};
+use crate::domain::pack::{
+    is_pack_image_name, PackCard, PackFile, PackManifest, PackSignature, CARDS_FILE, IMAGES_DIR,
+    MANIFEST_FILE, MAX_ENTRY_BYTES, MAX_PACK_BYTES, PACK_FORMAT_VERSION, PACK_IMAGE_EXTS, PACK_LANGUAGES,
+    SIGNATURE_FILE,
+};
+use crate::domain::region::Region;
+use crate::domain::role::Permission;
+use crate::domain::validation::{validate_card, CardFields, ValidCardFields};
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+use crate::services::card_service::region_json;
+use crate::services::duplicate_service::{term_key, DuplicateService};
+use crate::utils::assets::AssetRoots;
+use crate::utils::security::{
+    ed25519_generate, ed25519_sign, ed25519_verify, is_ed25519_public_key, sha256_hex,
+};
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+fn pack_error(msg: impl Into<String>) -> AppError {
+    AppError::new("PACK_INVALID", msg)
+}
+
+#[derive(sqlx::FromRow, Debug)]
+struct PackCardRow {
+    id: i64,
+    category: String,
+    english: String,
+    latin: String,
+    image_path: String,
+    region: Option<Json<Region>>,
+    synonyms: Option<String>,
+    description: Option<String>,
+}
+
+#[derive(sqlx::FromRow)]
+struct KnownImageRow {
+    id: i64,
+    image_path: String,
+    has_region: bool,
+    image_hash: String,
+}
+
+// Existing card an incoming image can be matched against
+struct KnownImage {
+    card_id: i64,
+    image_path: String,
+    has_region: bool,
+}
+
+// Card from the pack that passed every check
+struct IncomingCard {
+    row: usize,
+    category: Category,
+    card: PackCard,
+    image_path: String,
+    image_hash: String, // of the pack bytes, the file itself is written after the inserts
+    region: Option<String>,
+}
+
+fn image_ext(path: &str) -> Option<String> {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_lowercase())
+        .filter(|e| PACK_IMAGE_EXTS.contains(&e.as_str()))
+}
+
+struct OpenedPack {
+    manifest: PackManifest,
+    manifest_bytes: Vec<u8>,
+    signature: Option<PackSignature>,
+    files: HashMap<String, Vec<u8>>,
+}
+
+// Reads the manifest and every file it lists, checking the checksums.
+fn read_pack(path: &Path) -> Result<OpenedPack, AppError> {
+    let file = File::open(path)
+        .map_err(|e| AppError::new("PACK_UNREADABLE", format!("Cannot open pack: {e}")))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| pack_error(format!("Not a pack archive: {e}")))?;
+
+    let mut budget = MAX_PACK_BYTES;
+
+    let manifest_bytes = read_entry(&mut archive, MANIFEST_FILE, &mut budget)?;
+    let manifest: PackManifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| pack_error(format!("Bad manifest: {e}")))?;
+
+    if manifest.format_version != PACK_FORMAT_VERSION {
+        return Err(AppError::new(
+            "PACK_VERSION_UNSUPPORTED",
+            format!("Pack format {} is not supported.", manifest.format_version),
+        ));
+    }
+
+    if manifest.file(CARDS_FILE).is_none() {
+        return Err(pack_error("Manifest does not list cards.json."));
+    }
+
+    let mut languages = manifest.languages.clone();
+    languages.sort();
+    languages.dedup();
+    if languages.len() != manifest.languages.len()
+        || languages.len() != PACK_LANGUAGES.len()
+        || !languages.iter().all(|l| PACK_LANGUAGES.contains(&l.as_str()))
+    {
+        return Err(pack_error(format!(
+            "Pack languages must be {}.",
+            PACK_LANGUAGES.join(" and ")
+        )));
+    }
+
+    let has_signature = archive.file_names().any(|n| n == SIGNATURE_FILE);
+    let signature = if has_signature {
+        let bytes = read_entry(&mut archive, SIGNATURE_FILE, &mut budget)?;
+        let sig: PackSignature = serde_json::from_slice(&bytes)
+            .map_err(|e| pack_error(format!("Bad signature file: {e}")))?;
+        Some(sig)
+    } else {
+        None
+    };
+
+    let mut files = HashMap::new();
+
+    for f in &manifest.files {
+        if f.path != CARDS_FILE && !is_pack_image_name(&f.path) {
+            return Err(pack_error(format!("Unexpected file in manifest: {}", f.path)));
+        }
+
+        let bytes = read_entry(&mut archive, &f.path, &mut budget)?;
+        let hash = sha256_hex(&bytes);
+
+        let name_matches = match f.path.strip_prefix(IMAGES_DIR) {
+            Some(name) => name.starts_with(&hash),
+            None => true,
+        };
+
+        if hash != f.sha256.to_lowercase() || !name_matches {
+            return Err(AppError::new(
+                "PACK_CHECKSUM_MISMATCH",
+                format!("Checksum mismatch for {}.", f.path),
+            ));
+        }
+
+        files.insert(f.path.clone(), bytes);
+    }
+
+    Ok(OpenedPack {
+        manifest,
+        manifest_bytes,
+        signature,
+        files,
+    })
+}
+
+// "UNSIGNED", "UNTRUSTED" (valid signature, unknown key) or "VERIFIED" + publisher name.
+// A signature that does not match the manifest means the pack was changed: refuse it.
+async fn signature_status(
+    pool: &SqlitePool,
+    pack: &OpenedPack,
+) -> Result<(&'static str, Option<String>), AppError> {
+    let Some(sig) = &pack.signature else {
+        return Ok(("UNSIGNED", None));
+    };
+
+    if !ed25519_verify(&sig.public_key, &sig.signature, &pack.manifest_bytes) {
+        return Err(AppError::new(
+            "PACK_SIGNATURE_INVALID",
+            "Pack signature does not match its manifest.",
+        ));
+    }
+
+    let publisher = sqlx::query_scalar::<_, String>(
+        r#"SELECT name FROM trusted_publishers WHERE public_key = ?1"#,
+    )
+    .bind(sig.public_key.trim().to_lowercase())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    match publisher {
+        Some(name) => Ok(("VERIFIED", Some(name))),
+        None => Ok(("UNTRUSTED", None)),
+    }
+}
+
+// Reads one entry, refusing anything over MAX_ENTRY_BYTES or over what is left
+// of the whole pack's `budget`. The declared size is not trusted: reading stops
+// one byte past the limit.
+fn read_entry(archive: &mut ZipArchive<File>, name: &str, budget: &mut u64) -> Result<Vec<u8>, AppError> {
+    let entry = archive
+        .by_name(name)
+        .map_err(|_| pack_error(format!("Missing {name} in pack.")))?;
+
+    let limit = MAX_ENTRY_BYTES.min(*budget);
+    let too_large = || AppError::new("PACK_TOO_LARGE", format!("{name} is too large."));
+
+    if entry.size() > limit {
+        return Err(too_large());
+    }
+
+    let mut bytes = Vec::new();
+    entry
+        .take(limit + 1)
+        .read_to_end(&mut bytes)
+        .map_err(|e| pack_error(format!("Cannot read {name}: {e}")))?;
+
+    if bytes.len() as u64 > limit {
+        return Err(too_large());
+    }
+
+    *budget -= bytes.len() as u64;
+    Ok(bytes)
+}
+
+async fn publisher_by_id(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<Option<TrustedPublisher>, AppError> {
+    sqlx::query_as::<_, TrustedPublisher>(
+        r#"SELECT id, name, public_key, added_by, created_at FROM trusted_publishers WHERE id = ?1"#,
+    )
+    .bind(id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| format!("DB error: {e}").into())
+}
+
+async fn publisher_by_key(
+    conn: &mut SqliteConnection,
+    public_key: &str,
+) -> Result<Option<TrustedPublisher>, AppError> {
+    sqlx::query_as::<_, TrustedPublisher>(
+        r#"SELECT id, name, public_key, added_by, created_at FROM trusted_publishers WHERE public_key = ?1"#,
+    )
+    .bind(public_key)
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| format!("DB error: {e}").into())
+}
+
+fn write_entry(zip: &mut ZipWriter<File>, name: &str, bytes: &[u8]) -> Result<(), AppError> {
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file(name, options)
+        .map_err(|e| format!("Pack write failed: {e}"))?;
+    zip.write_all(bytes)
+        .map_err(|e| format!("Pack write failed: {e}"))?;
+
+    Ok(())
+}
+
+pub struct PackService;
+
+impl PackService {
+    pub async fn export_pack(
+        pool: &SqlitePool,
+        assets: &AssetRoots,
+        session_token: String,
+        card_ids: Vec<i64>,
+        file_path: String,
+        name: Option<String>,
+        signing_key: Option<String>,
+    ) -> Result<PackExportResponse, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::CardsImport).await?;
+
+        if card_ids.is_empty() {
+            return Err(AppError::new("PACK_EMPTY", "Select at least one card to export."));
+        }
+
+        let mut cards = Vec::with_capacity(card_ids.len());
+        // archive name -> bytes, so an image shared by many cards is stored once
+        let mut images: HashMap<String, Vec<u8>> = HashMap::new();
+
+        for id in &card_ids {
+            let row = sqlx::query_as::<_, PackCardRow>(
+                r#"
+                SELECT id, category, english, latin, image_path, region, synonyms, description
+                FROM cards
+                WHERE id = ?1 AND status = 'APPROVED' AND archived_at IS NULL
+                "#,
+            )
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| format!("DB error: {e}"))?
+            .ok_or_else(|| AppError::new("CARD_NOT_FOUND", format!("Card {id} not found or not published.")))?;
+
+            let ext = image_ext(&row.image_path).ok_or_else(|| {
+                AppError::new(
+                    "IMAGE_TYPE_UNSUPPORTED",
+                    format!("Card {}: only png, jpg, webp and gif images can be packed.", row.id),
+                )
+            })?;
+
+            let disk_path = assets.resolve(&row.image_path).map_err(|e| {
+                AppError::new(e.code(), format!("Card {}: {e}", row.id))
+            })?;
+            let bytes = std::fs::read(&disk_path).map_err(|e| format!("Read image failed: {e}"))?;
+
+            let image = format!("{IMAGES_DIR}{}.{}", sha256_hex(&bytes), ext);
+            images.entry(image.clone()).or_insert(bytes);
+
+            cards.push(PackCard {
+                category: row.category,
+                english: row.english,
+                latin: row.latin,
+                image,
+                region: row.region.map(|r| r.0),
+                synonyms: row.synonyms,
+                description: row.description,
+            });
+        }
+
+        let cards_json =
+            serde_json::to_vec_pretty(&cards).map_err(|e| format!("Encode cards failed: {e}"))?;
+
+        let mut files = vec![PackFile {
+            path: CARDS_FILE.to_string(),
+            sha256: sha256_hex(&cards_json),
+        }];
+        let mut image_names: Vec<&String> = images.keys().collect();
+        image_names.sort();
+        for name in &image_names {
+            files.push(PackFile {
+                path: (*name).clone(),
+                sha256: sha256_hex(&images[*name]),
+            });
+        }
+
+        let manifest = PackManifest {
+            format_version: PACK_FORMAT_VERSION,
+            name: name
+                .map(|n| n.trim().to_string())
+                .filter(|n| !n.is_empty())
+                .unwrap_or_else(|| "Hangman+ pack".to_string()),
+            created_at: now_unix(),
+            languages: PACK_LANGUAGES.iter().map(|l| l.to_string()).collect(),
+            card_count: cards.len(),
+            files,
+        };
+        let manifest_json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| format!("Encode manifest failed: {e}"))?;
+
+        let signature = match signing_key.as_deref().map(str::trim).filter(|k| !k.is_empty()) {
+            Some(key) => {
+                let (public_key, signature) = ed25519_sign(key, &manifest_json)
+                    .map_err(|msg| AppError::new("SIGNING_KEY_INVALID", msg))?;
+                let sig = PackSignature {
+                    public_key,
+                    signature,
+                };
+                Some(serde_json::to_vec_pretty(&sig).map_err(|e| format!("Encode signature failed: {e}"))?)
+            }
+            None => None,
+        };
+
+        let mut target = PathBuf::from(file_path.trim());
+        if target.extension().and_then(|e| e.to_str()) != Some("hpack") {
+            target.set_extension("hpack");
+        }
+
+        let file = File::create(&target).map_err(|e| format!("Create pack failed: {e}"))?;
+        let mut zip = ZipWriter::new(file);
+
+        write_entry(&mut zip, MANIFEST_FILE, &manifest_json)?;
+        if let Some(sig) = &signature {
+            write_entry(&mut zip, SIGNATURE_FILE, sig)?;
+        }
+        write_entry(&mut zip, CARDS_FILE, &cards_json)?;
+        for name in &image_names {
+            write_entry(&mut zip, name, &images[*name])?;
+        }
+
+        zip.finish().map_err(|e| format!("Pack write failed: {e}"))?;
+
+        let response = PackExportResponse {
+            file_path: target.display().to_string(),
+            card_count: cards.len(),
+            image_count: image_names.len(),
+            signed: signature.is_some(),
+        };
+
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|e| format!("DB connect failed: {e}"))?;
+        let summary = serde_json::json!({ "name": manifest.name, "cardIds": card_ids, "export": response });
+        audit_log::record(&mut conn, user_id, "PACK_EXPORT", "PACK", None, None, json(&summary)).await?;
+
+        Ok(response)
+    }
+
+    // Manifest summary and signature check, without importing anything
+    pub async fn inspect_pack(
+        pool: &SqlitePool,
+        session_token: String,
+        file_path: String,
+    ) -> Result<PackInspectResponse, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::CardsImport).await?;
+
+        let pack = read_pack(Path::new(file_path.trim()))?;
+        let (signature, publisher) = signature_status(pool, &pack).await?;
+
+        Ok(PackInspectResponse {
+            name: pack.manifest.name,
+            format_version: pack.manifest.format_version,
+            created_at: pack.manifest.created_at,
+            languages: pack.manifest.languages,
+            card_count: pack.manifest.card_count,
+            signature: signature.to_string(),
+            publisher,
+        })
+    }
+
+    pub async fn import_pack(
+        pool: &SqlitePool,
+        assets: &AssetRoots,
+        session_token: String,
+        file_path: String,
+        approve: bool,
+    ) -> Result<PackImportReport, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::CardsImport).await?;
+
+        let pack = read_pack(Path::new(file_path.trim()))?;
+        let (signature, publisher) = signature_status(pool, &pack).await?;
+        let OpenedPack {
+            manifest, files, ..
+        } = pack;
+
+        let pack_cards: Vec<PackCard> = serde_json::from_slice(&files[CARDS_FILE])
+            .map_err(|e| pack_error(format!("Bad cards.json: {e}")))?;
+
+        if pack_cards.len() != manifest.card_count {
+            return Err(pack_error("Card count does not match the manifest."));
+        }
+
+        // what we already have: terms per category and stored image content hashes
+        let mut terms = DuplicateService::existing_terms(pool).await?;
+
+        let known = sqlx::query_as::<_, KnownImageRow>(
+            r#"
+            SELECT id, image_path, region IS NOT NULL AS has_region, image_hash
+            FROM cards
+            WHERE image_hash IS NOT NULL
+            ORDER BY id ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        let mut known_images: HashMap<String, KnownImage> = HashMap::new();
+        for c in known {
+            known_images.entry(c.image_hash).or_insert(KnownImage {
+                card_id: c.id,
+                image_path: c.image_path,
+                has_region: c.has_region,
+            });
+        }
+
+        let mut rows = Vec::with_capacity(pack_cards.len());
+        let mut incoming = Vec::new();
+        // new image files, written only once every card row is in
+        let mut new_images: Vec<(String, &[u8])> = Vec::new();
+
+        for mut card in pack_cards {
+            let mut row = PackImportRow {
+                english: card.english.trim().to_string(),
+                latin: card.latin.trim().to_string(),
+                status: "ERROR".into(),
+                message: None,
+                card_id: None,
+            };
+
+            // the image is checked against the archive below
+            let fields = CardFields {
+                category: Some(&card.category),
+                english: &card.english,
+                latin: &card.latin,
+                image_path: None,
+                region: card.region.as_ref(),
+            };
+
+            let (category, english, latin) = match validate_card(&fields) {
+                Ok(ValidCardFields {
+                    category: Some(category),
+                    english,
+                    latin,
+                }) => (category, english, latin),
+                Ok(_) => {
+                    row.message = Some("Category is required.".into());
+                    rows.push(row);
+                    continue;
+                }
+                Err(errors) => {
+                    let messages: Vec<_> = errors.into_iter().map(|e| e.message).collect();
+                    row.message = Some(messages.join(" "));
+                    rows.push(row);
+                    continue;
+                }
+            };
+            row.english = english.clone();
+            row.latin = latin.clone();
+            card.english = english;
+            card.latin = latin;
+
+            let region = region_json(card.region.as_ref())?;
+
+            let term_keys = [
+                term_key(category.as_str(), &row.english),
+                term_key(category.as_str(), &row.latin),
+            ];
+
+            if let Some(id) = term_keys.iter().find_map(|k| terms.get(k)) {
+                row.status = "DUPLICATE_TERM".into();
+                row.message = Some("A card with this term already exists.".into());
+                row.card_id = Some(*id).filter(|id| *id > 0);
+                rows.push(row);
+                continue;
+            }
+
+            let Some((bytes, ext)) = files
+                .get(&card.image)
+                .zip(image_ext(&card.image))
+                .filter(|_| is_pack_image_name(&card.image))
+            else {
+                row.message = Some(format!("Image {} is not in the pack.", card.image));
+                rows.push(row);
+                continue;
+            };
+
+            let hash = sha256_hex(bytes);
+
+            // same picture already in use: a plain picture card is a duplicate,
+            // a hotspot card just reuses the existing diagram file
+            let image_path = match known_images.get(&hash) {
+                Some(known) if !known.has_region && card.region.is_none() => {
+                    row.status = "DUPLICATE_IMAGE".into();
+                    row.message = Some("A card with the same image already exists.".into());
+                    row.card_id = Some(known.card_id).filter(|id| *id > 0);
+                    rows.push(row);
+                    continue;
+                }
+                Some(known) => known.image_path.clone(),
+                None => {
+                    let path = AssetRoots::content_image_path("packs", bytes, &ext)?;
+                    new_images.push((path.clone(), bytes.as_slice()));
+                    path
+                }
+            };
+
+            known_images.entry(hash.clone()).or_insert(KnownImage {
+                card_id: 0, // not inserted yet
+                image_path: image_path.clone(),
+                has_region: card.region.is_some(),
+            });
+
+            // later rows in the same pack must not repeat these terms
+            for k in term_keys {
+                terms.entry(k).or_insert(0);
+            }
+
+            row.status = "IMPORTED".into();
+            incoming.push(IncomingCard {
+                row: rows.len(),
+                category,
+                card,
+                image_path,
+                image_hash: hash,
+                region,
+            });
+            rows.push(row);
+        }
+
+        // only packs signed by a trusted publisher may skip moderation
+        let card_status = if approve && signature == "VERIFIED" {
+            "APPROVED"
+        } else {
+            "PENDING"
+        };
+        let now = now_unix();
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        for c in &incoming {
+            let id = sqlx::query_scalar::<_, i64>(
+                r#"
+                INSERT INTO cards (category, english, latin, image_path, region, image_hash, synonyms, description, status, created_by, created_at)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)
+                RETURNING id
+                "#,
+            )
+            .bind(c.category.as_str())
+            .bind(&c.card.english)
+            .bind(&c.card.latin)
+            .bind(&c.image_path)
+            .bind(&c.region)
+            .bind(&c.image_hash)
+            .bind(c.card.synonyms.as_deref().map(str::trim).filter(|s| !s.is_empty()))
+            .bind(c.card.description.as_deref().map(str::trim).filter(|s| !s.is_empty()))
+            .bind(card_status)
+            .bind(user_id)
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| format!("Pack insert failed: {e}"))?;
+
+            let note = format!("Imported from pack {}", manifest.name);
+            card_revisions::record(&mut tx, id, "CREATE", user_id, None, Some(&note)).await?;
+
+            rows[c.row].card_id = Some(id);
+        }
+
+        let summary = serde_json::json!({
+            "name": manifest.name,
+            "signature": signature,
+            "publisher": publisher,
+            "cardStatus": card_status,
+            "cardIds": rows.iter().filter_map(|r| r.card_id).collect::<Vec<_>>(),
+        });
+        audit_log::record(&mut tx, user_id, "PACK_IMPORT", "PACK", None, None, json(&summary)).await?;
+
+        // files last: a failure here or in the commit removes what was written
+        // (the transaction rolls back when dropped)
+        let mut written = Vec::new();
+        let mut result = Ok(());
+
+        for (image_path, bytes) in &new_images {
+            match assets.store_image(image_path, bytes) {
+                Ok(created) => written.extend(created),
+                Err(e) => {
+                    result = Err(AppError::from(e));
+                    break;
+                }
+            }
+        }
+        if result.is_ok() {
+            result = tx
+                .commit()
+                .await
+                .map_err(|e| format!("Pack commit failed: {e}").into());
+        }
+        if let Err(e) = result {
+            for file in written {
+                let _ = std::fs::remove_file(file);
+            }
+            return Err(e);
+        }
+
+        Ok(PackImportReport {
+            name: manifest.name,
+            format_version: manifest.format_version,
+            languages: manifest.languages,
+            signature: signature.to_string(),
+            publisher,
+            card_status: card_status.to_string(),
+            imported: incoming.len(),
+            skipped: rows.len() - incoming.len(),
+            rows,
+        })
+    }
+
+    // ---------- trusted publishers ----------
+
+    pub async fn list_trusted_publishers(
+        pool: &SqlitePool,
+        session_token: String,
+    ) -> Result<Vec<TrustedPublisher>, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::PacksManage).await?;
+
+        sqlx::query_as::<_, TrustedPublisher>(
+            r#"
+            SELECT id, name, public_key, added_by, created_at
+            FROM trusted_publishers
+            ORDER BY name ASC
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}").into())
+    }
+
+    pub async fn add_trusted_publisher(
+        pool: &SqlitePool,
+        session_token: String,
+        name: String,
+        public_key: String,
+    ) -> Result<i64, AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::PacksManage).await?;
+
+        if name.trim().is_empty() {
+            return Err(AppError::new("PUBLISHER_NAME_EMPTY", "Publisher name is required."));
+        }
+        if !is_ed25519_public_key(&public_key) {
+            return Err(AppError::new(
+                "PUBLIC_KEY_INVALID",
+                "Public key must be a 32-byte ed25519 key in hex.",
+            ));
+        }
+
+        let public_key = public_key.trim().to_lowercase();
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let before = publisher_by_key(&mut tx, &public_key).await?;
+
+        let id = sqlx::query_scalar::<_, i64>(
+            r#"
+            INSERT INTO trusted_publishers (name, public_key, added_by, created_at)
+            VALUES (?1, ?2, ?3, ?4)
+            ON CONFLICT(public_key) DO UPDATE SET name = excluded.name
+            RETURNING id
+            "#,
+        )
+        .bind(name.trim())
+        .bind(&public_key)
+        .bind(user_id)
+        .bind(now_unix())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| format!("Insert failed: {e}"))?;
+
+        let after = publisher_by_id(&mut tx, id).await?;
+        audit_log::record(&mut tx, user_id, "PUBLISHER_ADD", "PUBLISHER", Some(id), json(&before), json(&after))
+            .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
+        Ok(id)
+    }
+
+    pub async fn remove_trusted_publisher(
+        pool: &SqlitePool,
+        session_token: String,
+        id: i64,
+    ) -> Result<(), AppError> {
+        let (user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::PacksManage).await?;
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let before = publisher_by_id(&mut tx, id)
+            .await?
+            .ok_or_else(|| AppError::new("PUBLISHER_NOT_FOUND", "Publisher not found."))?;
+
+        sqlx::query(r#"DELETE FROM trusted_publishers WHERE id = ?1"#)
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Delete failed: {e}"))?;
+
+        audit_log::record(&mut tx, user_id, "PUBLISHER_REMOVE", "PUBLISHER", Some(id), json(&before), None)
+            .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Commit failed: {e}"))?;
+
+        Ok(())
+    }
+
+    // New department key pair; the secret key is never stored by the app
+    pub async fn generate_signing_key(
+        pool: &SqlitePool,
+        session_token: String,
+    ) -> Result<SigningKeyResponse, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::PacksManage).await?;
+
+        let (secret_key, public_key) = ed25519_generate();
+        Ok(SigningKeyResponse {
+            secret_key,
+            public_key,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::{login_as, temp_pool};
+    use tempfile::TempDir;
+
+    // Admin session and one approved card with an image on disk
+    async fn library() -> (TempDir, SqlitePool, AssetRoots, String) {
+        let (dir, pool) = temp_pool().await;
+        let token = login_as(&pool, "admin", "ADMIN").await;
+
+        std::fs::create_dir_all(dir.path().join("public/cards/bones")).unwrap();
+        std::fs::write(dir.path().join("public/cards/bones/femur.png"), b"femur").unwrap();
+        let assets = AssetRoots::new(vec![dir.path().join("public")])
+            .with_writable_root(dir.path().join("data"));
+
+        sqlx::query(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at)
+            VALUES ('BONES', 'Thigh bone', 'Femur', '/cards/bones/femur.png', 'APPROVED', 0)
+            "#,
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        (dir, pool, assets, token)
+    }
+
+    async fn export(dir: &TempDir, pool: &SqlitePool, assets: &AssetRoots, token: &str, key: Option<String>) -> String {
+        let target = dir.path().join("deck.hpack").display().to_string();
+        PackService::export_pack(pool, assets, token.to_string(), vec![1], target, None, key)
+            .await
+            .unwrap()
+            .file_path
+    }
+
+    // Rewrites the archive, passing every entry through `edit`
+    fn rewrite(path: &str, edit: impl Fn(&str, Vec<u8>) -> Vec<u8>) {
+        let mut archive = ZipArchive::new(File::open(path).unwrap()).unwrap();
+        let mut entries = Vec::new();
+        for i in 0..archive.len() {
+            let mut entry = archive.by_index(i).unwrap();
+            let mut bytes = Vec::new();
+            entry.read_to_end(&mut bytes).unwrap();
+            entries.push((entry.name().to_string(), bytes));
+        }
+
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        for (name, bytes) in entries {
+            let bytes = edit(&name, bytes);
+            write_entry(&mut zip, &name, &bytes).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[tokio::test]
+    async fn signed_pack_is_verified_and_tampering_is_refused() {
+        let (dir, pool, assets, token) = library().await;
+        let key = PackService::generate_signing_key(&pool, token.clone()).await.unwrap();
+        let path = export(&dir, &pool, &assets, &token, Some(key.secret_key)).await;
+
+        let inspect = PackService::inspect_pack(&pool, token.clone(), path.clone()).await.unwrap();
+        assert_eq!(inspect.signature, "UNTRUSTED");
+
+        PackService::add_trusted_publisher(&pool, token.clone(), "Anatomy".into(), key.public_key.clone())
+            .await
+            .unwrap();
+        let inspect = PackService::inspect_pack(&pool, token.clone(), path.clone()).await.unwrap();
+        assert_eq!((inspect.signature.as_str(), inspect.publisher.as_deref()), ("VERIFIED", Some("Anatomy")));
+
+        // a signature by another key, claimed to be the trusted one
+        let (_, other_public) = ed25519_generate();
+        PackService::add_trusted_publisher(&pool, token.clone(), "Other".into(), other_public.clone())
+            .await
+            .unwrap();
+        let original = std::fs::read(&path).unwrap();
+        rewrite(&path, |name, bytes| {
+            if name != SIGNATURE_FILE {
+                return bytes;
+            }
+            let mut sig: PackSignature = serde_json::from_slice(&bytes).unwrap();
+            sig.public_key = other_public.clone();
+            serde_json::to_vec(&sig).unwrap()
+        });
+        let err = PackService::inspect_pack(&pool, token.clone(), path.clone()).await.err().unwrap();
+        assert_eq!(err.code, "PACK_SIGNATURE_INVALID");
+
+        // tampered manifest
+        std::fs::write(&path, original).unwrap();
+        rewrite(&path, |name, bytes| {
+            if name != MANIFEST_FILE {
+                return bytes;
+            }
+            let mut manifest: PackManifest = serde_json::from_slice(&bytes).unwrap();
+            manifest.name = "Renamed".into();
+            serde_json::to_vec(&manifest).unwrap()
+        });
+        let err = PackService::inspect_pack(&pool, token, path).await.err().unwrap();
+        assert_eq!(err.code, "PACK_SIGNATURE_INVALID");
+    }
+
+    #[tokio::test]
+    async fn changed_files_fail_the_checksum() {
+        let (dir, pool, assets, token) = library().await;
+        let path = export(&dir, &pool, &assets, &token, None).await;
+
+        rewrite(&path, |name, bytes| {
+            if name.starts_with(IMAGES_DIR) {
+                b"not a femur".to_vec()
+            } else {
+                bytes
+            }
+        });
+
+        let err = PackService::inspect_pack(&pool, token, path).await.err().unwrap();
+        assert_eq!(err.code, "PACK_CHECKSUM_MISMATCH");
+    }
+
+    #[tokio::test]
+    async fn unknown_languages_are_refused() {
+        let (dir, pool, assets, token) = library().await;
+        let path = export(&dir, &pool, &assets, &token, None).await;
+
+        rewrite(&path, |name, bytes| {
+            if name != MANIFEST_FILE {
+                return bytes;
+            }
+            let mut manifest: PackManifest = serde_json::from_slice(&bytes).unwrap();
+            manifest.languages = vec!["EN".into(), "EN".into()];
+            serde_json::to_vec(&manifest).unwrap()
+        });
+
+        let err = PackService::inspect_pack(&pool, token, path).await.err().unwrap();
+        assert_eq!(err.code, "PACK_INVALID");
+    }
+
+    #[tokio::test]
+    async fn only_published_raster_cards_are_exported() {
+        let (dir, pool, assets, token) = library().await;
+        let target = dir.path().join("deck.hpack").display().to_string();
+
+        for status in ["PENDING", "REJECTED"] {
+            sqlx::query("UPDATE cards SET status = ?1 WHERE id = 1")
+                .bind(status)
+                .execute(&pool)
+                .await
+                .unwrap();
+            let err = PackService::export_pack(&pool, &assets, token.clone(), vec![1], target.clone(), None, None)
+                .await
+                .err()
+                .unwrap();
+            assert_eq!(err.code, "CARD_NOT_FOUND");
+        }
+
+        sqlx::query("UPDATE cards SET status = 'APPROVED', archived_at = 1 WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let err = PackService::export_pack(&pool, &assets, token.clone(), vec![1], target.clone(), None, None)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, "CARD_NOT_FOUND");
+
+        std::fs::write(dir.path().join("public/cards/bones/femur.svg"), b"<svg/>").unwrap();
+        sqlx::query("UPDATE cards SET archived_at = NULL, image_path = '/cards/bones/femur.svg' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let err = PackService::export_pack(&pool, &assets, token, vec![1], target, None, None)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err.code, "IMAGE_TYPE_UNSUPPORTED");
+    }
+
+    #[test]
+    fn only_raster_images_are_accepted_from_a_pack() {
+        let hash = sha256_hex(b"femur");
+        assert!(is_pack_image_name(&format!("{IMAGES_DIR}{hash}.webp")));
+        assert!(!is_pack_image_name(&format!("{IMAGES_DIR}{hash}.svg")));
+        assert!(!is_pack_image_name(&format!("{IMAGES_DIR}{hash}.html")));
+        assert!(!is_pack_image_name(&format!("{IMAGES_DIR}{hash}.PNG")));
+    }
+
+    #[tokio::test]
+    async fn removing_an_unknown_publisher_is_not_found() {
+        let (_dir, pool, _assets, token) = library().await;
+        let err = PackService::remove_trusted_publisher(&pool, token, 42).await.err().unwrap();
+        assert_eq!(err.code, "PUBLISHER_NOT_FOUND");
+    }
+
+    #[tokio::test]
+    async fn import_stores_images_by_content() {
+        let (dir, pool, assets, token) = library().await;
+        let path = export(&dir, &pool, &assets, &token, None).await;
+
+        let (_other_dir, other_pool) = temp_pool().await;
+        let other_token = login_as(&other_pool, "admin", "ADMIN").await;
+        let other_assets = AssetRoots::new(vec![]).with_writable_root(dir.path().join("other"));
+
+        let report = PackService::import_pack(&other_pool, &other_assets, other_token, path, false)
+            .await
+            .unwrap();
+        assert_eq!(report.imported, 1);
+
+        let image_path: String = sqlx::query_scalar("SELECT image_path FROM cards")
+            .fetch_one(&other_pool)
+            .await
+            .unwrap();
+        assert_eq!(image_path, format!("/cards/packs/{}.png", sha256_hex(b"femur")));
+        assert_eq!(std::fs::read(other_assets.resolve(&image_path).unwrap()).unwrap(), b"femur");
+
+        let image_hash: Option<String> = sqlx::query_scalar("SELECT image_hash FROM cards")
+            .fetch_one(&other_pool)
+            .await
+            .unwrap();
+        assert_eq!(image_hash, Some(sha256_hex(b"femur")));
+    }
+
+    #[tokio::test]
+    async fn import_matches_normalized_terms_and_stored_image_hashes() {
+        let (dir, pool, assets, token) = library().await;
+        let path = export(&dir, &pool, &assets, &token, None).await;
+
+        // same term, written differently
+        sqlx::query("UPDATE cards SET english = ' THIGH  bone.', latin = 'Os femoris' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = PackService::import_pack(&pool, &assets, token.clone(), path.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!((report.rows[0].status.as_str(), report.rows[0].card_id), ("DUPLICATE_TERM", Some(1)));
+
+        // other terms, same picture: found through the stored hash
+        sqlx::query("UPDATE cards SET english = 'Shin bone', latin = 'Tibia', image_hash = ?1 WHERE id = 1")
+            .bind(sha256_hex(b"femur"))
+            .execute(&pool)
+            .await
+            .unwrap();
+        let report = PackService::import_pack(&pool, &assets, token, path, false)
+            .await
+            .unwrap();
+        assert_eq!((report.rows[0].status.as_str(), report.rows[0].card_id), ("DUPLICATE_IMAGE", Some(1)));
+        assert_eq!(report.imported, 0);
+    }
+}
@@ -1,9 +1,11 @@
 use sqlx::SqlitePool;
 
+use crate::errors::AppError;
 use crate::utils::security::{hash_password, verify_password};
 use crate::services::auth_service::AuthService;
+use crate::services::session_service::revoke_other_sessions;
 
-fn validate_profile(first_name: &str, last_name: &str, username: &str, email: &str) -> Result<(), String> {
+fn validate_profile(first_name: &str, last_name: &str, username: &str, email: &str) -> Result<(), AppError> {
     if first_name.trim().len() < 2 {
         return Err("First name must be at least 2 characters.".into());
     }
@@ -19,7 +21,7 @@ fn validate_profile(first_name: &str, last_name: &str, username: &str, email: &s
     Ok(())
 }
 
-fn validate_new_password(old_password: &str, new_password: &str) -> Result<(), String> {
+fn validate_new_password(old_password: &str, new_password: &str) -> Result<(), AppError> {
     if old_password.is_empty() {
         return Err("Enter old password.".into());
     }
@@ -47,7 +49,7 @@ struct PasswordRow {
 pub struct ProfileService;
 
 impl ProfileService {
-    pub async fn get_profile(pool: &SqlitePool, session_token: String) -> Result<ProfileRow, String> {
+    pub async fn get_profile(pool: &SqlitePool, session_token: String) -> Result<ProfileRow, AppError> {
         let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
 
         let row = sqlx::query_as::<_, ProfileRow>(
@@ -78,7 +80,7 @@ impl ProfileService {
         last_name: String,
         username: String,
         email: String,
-    ) -> Result<(), String> {
+    ) -> Result<(), AppError> {
         let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
 
         validate_profile(&first_name, &last_name, &username, &email)?;
@@ -129,8 +131,9 @@ impl ProfileService {
         session_token: String,
         old_password: String,
         new_password: String,
-    ) -> Result<(), String> {
-        let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+    ) -> Result<(), AppError> {
+        let session = AuthService::require_session(pool, &session_token).await?;
+        let user_id = session.user_id;
 
         validate_new_password(&old_password, &new_password)?;
 
@@ -153,6 +156,8 @@ impl ProfileService {
 
         let new_hash = hash_password(&new_password)?;
 
+        let mut tx = pool.begin().await.map_err(|e| format!("DB error: {e}"))?;
+
         sqlx::query(
             r#"
             UPDATE users
@@ -162,10 +167,45 @@ impl ProfileService {
         )
         .bind(new_hash)
         .bind(user_id)
-        .execute(pool)
+        .execute(&mut *tx)
         .await
         .map_err(|e| format!("Password update failed: {e}"))?;
 
+        // every other device has to log in again with the new password
+        revoke_other_sessions(&mut tx, user_id, session.session_id).await?;
+
+        tx.commit().await.map_err(|e| format!("DB error: {e}"))?;
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use crate::db::test_util::{login_as, temp_pool};
+
+    #[tokio::test]
+    async fn change_password_signs_out_other_sessions() {
+        let (_dir, pool) = temp_pool().await;
+        let laptop = login_as(&pool, "student", "USER").await;
+        let phone = AuthService::login_user(&pool, "student".into(), "password1".into(), None, &AppConfig::default())
+            .await
+            .unwrap()
+            .session_token;
+
+        ProfileService::change_password(&pool, laptop.clone(), "password1".into(), "password2".into())
+            .await
+            .unwrap();
+
+        assert!(AuthService::validate_session(&pool, laptop).await.unwrap());
+        assert!(!AuthService::validate_session(&pool, phone).await.unwrap());
+
+        let config = AppConfig::default();
+        assert!(AuthService::login_user(&pool, "student".into(), "password1".into(), None, &config).await.is_err());
+        AuthService::login_user(&pool, "student".into(), "password2".into(), None, &config)
+            .await
+            .unwrap();
+    }
+}
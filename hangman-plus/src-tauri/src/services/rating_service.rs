@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use hangman_core::rating::{play, Rated, DEFAULT_RATING};
+use sqlx::{SqliteConnection, SqlitePool};
+use time::OffsetDateTime;
+
+use crate::domain::category::Category;
+use crate::domain::dto::UserRating;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+#[derive(sqlx::FromRow)]
+struct CardRating {
+    category: String,
+    rating: f64,
+    rating_games: i64,
+}
+
+// Rates one resolved attempt: the player against the card, per category.
+// Attempts on cards that no longer exist are left unrated.
+pub async fn record_attempt(
+    conn: &mut SqliteConnection,
+    user_id: i64,
+    card_id: i64,
+    won: bool,
+) -> Result<(), AppError> {
+    let card = sqlx::query_as::<_, CardRating>(
+        r#"SELECT category, rating, rating_games FROM cards WHERE id = ?1"#,
+    )
+    .bind(card_id)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?;
+
+    let Some(card) = card else {
+        return Ok(());
+    };
+
+    let player = sqlx::query_as::<_, (f64, i64)>(
+        r#"SELECT rating, games FROM user_ratings WHERE user_id = ?1 AND category = ?2"#,
+    )
+    .bind(user_id)
+    .bind(&card.category)
+    .fetch_optional(&mut *conn)
+    .await
+    .map_err(|e| format!("DB error: {e}"))?
+    .map(|(rating, games)| Rated { rating, games })
+    .unwrap_or_default();
+
+    let (player, rated_card) = play(
+        player,
+        Rated {
+            rating: card.rating,
+            games: card.rating_games,
+        },
+        won,
+    );
+
+    sqlx::query(
+        r#"
+        INSERT INTO user_ratings (user_id, category, rating, games, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5)
+        ON CONFLICT (user_id, category)
+        DO UPDATE SET rating = excluded.rating, games = excluded.games, updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(user_id)
+    .bind(&card.category)
+    .bind(player.rating)
+    .bind(player.games)
+    .bind(now_unix())
+    .execute(&mut *conn)
+    .await
+    .map_err(|e| format!("Rating update failed: {e}"))?;
+
+    sqlx::query(r#"UPDATE cards SET rating = ?1, rating_games = ?2 WHERE id = ?3"#)
+        .bind(rated_card.rating)
+        .bind(rated_card.games)
+        .bind(card_id)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| format!("Rating update failed: {e}"))?;
+
+    Ok(())
+}
+
+pub struct RatingService;
+
+impl RatingService {
+    // Current player's rating in every category; unplayed ones start at the default
+    pub async fn get_my_ratings(pool: &SqlitePool, session_token: String) -> Result<Vec<UserRating>, AppError> {
+        let (user_id, _role) = AuthService::require_session_user(pool, &session_token).await?;
+
+        let played: HashMap<String, (f64, i64)> = sqlx::query_as::<_, (String, f64, i64)>(
+            r#"SELECT category, rating, games FROM user_ratings WHERE user_id = ?1"#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?
+        .into_iter()
+        .map(|(category, rating, games)| (category, (rating, games)))
+        .collect();
+
+        let ratings = Category::ALL
+            .iter()
+            .map(|c| {
+                let (rating, games) = played.get(c.as_str()).copied().unwrap_or((DEFAULT_RATING, 0));
+                UserRating {
+                    category: c.as_str().to_string(),
+                    rating,
+                    games,
+                }
+            })
+            .collect();
+
+        Ok(ratings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_util::temp_pool;
+
+    async fn ratings(conn: &mut SqliteConnection, card_id: i64) -> (f64, f64, i64) {
+        let player: f64 = sqlx::query_scalar("SELECT rating FROM user_ratings WHERE user_id = 1")
+            .fetch_one(&mut *conn)
+            .await
+            .unwrap();
+        let (card, games): (f64, i64) = sqlx::query_as("SELECT rating, rating_games FROM cards WHERE id = ?1")
+            .bind(card_id)
+            .fetch_one(&mut *conn)
+            .await
+            .unwrap();
+        (player, card, games)
+    }
+
+    #[tokio::test]
+    async fn win_and_loss_move_player_and_card_apart() {
+        let (_dir, pool) = temp_pool().await;
+        let card_id: i64 = sqlx::query_scalar(
+            r#"
+            INSERT INTO cards (category, english, latin, image_path, status, created_at)
+            VALUES ('BONES', 'Femur', 'Femur', '/cards/bones/femur.png', 'APPROVED', 0)
+            RETURNING id
+            "#,
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let mut tx = pool.begin().await.unwrap();
+
+        record_attempt(&mut tx, 1, card_id, true).await.unwrap();
+        let (player, card, games) = ratings(&mut tx, card_id).await;
+        assert!(player > DEFAULT_RATING && card < DEFAULT_RATING);
+        assert_eq!(games, 1);
+
+        record_attempt(&mut tx, 1, card_id, false).await.unwrap();
+        let (player_after, card_after, games) = ratings(&mut tx, card_id).await;
+        assert!(player_after < player && card_after > card);
+        assert_eq!(games, 2);
+
+        // nothing is visible outside the transaction until it commits
+        tx.rollback().await.unwrap();
+        let rated: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM user_ratings")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(rated, 0);
+    }
+}
@@ -0,0 +1,68 @@
+use sqlx::SqlitePool;
+
+use crate::db::card_search;
+use crate::domain::category::Category;
+use crate::domain::dto::CardSearchHit;
+use crate::domain::role::{Permission, Role};
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+pub struct SearchService;
+
+impl SearchService {
+    // Prefix search over terms, synonyms and descriptions, ranked with bm25.
+    // Card editors see every live card; everyone else only approved ones.
+    pub async fn search_cards(
+        pool: &SqlitePool,
+        session_token: String,
+        query: String,
+        category: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<CardSearchHit>, AppError> {
+        let (_user_id, role) = AuthService::require_session_user(pool, &session_token).await?;
+        let all_statuses = Role::parse(&role).is_some_and(|r| r.can(Permission::CardsEdit));
+
+        let category = match category.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+            Some(c) => Some(Category::parse(c).ok_or("Category must be ORGANS or BONES.")?),
+            None => None,
+        };
+
+        let Some(expr) = card_search::match_query(&query) else {
+            return Ok(Vec::new());
+        };
+
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+        // column weights: english, latin, synonyms, description
+        sqlx::query_as::<_, CardSearchHit>(
+            r#"
+            SELECT
+                c.id,
+                c.category,
+                c.english,
+                c.latin,
+                c.image_path,
+                c.status,
+                -bm25(cards_fts, 10.0, 10.0, 4.0, 1.0) AS score
+            FROM cards_fts
+            JOIN cards c ON c.id = cards_fts.rowid
+            WHERE cards_fts MATCH ?1
+              AND c.archived_at IS NULL
+              AND (?2 = 1 OR c.status = 'APPROVED')
+              AND (?3 IS NULL OR c.category = ?3)
+            ORDER BY score DESC, c.id ASC
+            LIMIT ?4
+            "#,
+        )
+        .bind(expr)
+        .bind(all_statuses)
+        .bind(category.map(|c| c.as_str()))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Search failed: {e}").into())
+    }
+}
@@ -0,0 +1,102 @@
+use sqlx::{SqliteConnection, SqlitePool};
+use time::OffsetDateTime;
+
+use crate::domain::dto::SessionItem;
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+// Revokes every live session of `user_id` except `keep_session_id`.
+// Also used by change_password so a leaked password stops working everywhere else.
+pub async fn revoke_other_sessions(
+    conn: &mut SqliteConnection,
+    user_id: i64,
+    keep_session_id: i64,
+) -> Result<u64, AppError> {
+    let res = sqlx::query(
+        r#"
+        UPDATE sessions
+        SET revoked_at = ?3
+        WHERE user_id = ?1 AND id <> ?2 AND revoked_at IS NULL
+        "#,
+    )
+    .bind(user_id)
+    .bind(keep_session_id)
+    .bind(now_unix())
+    .execute(conn)
+    .await
+    .map_err(|e| format!("Revoke sessions failed: {e}"))?;
+
+    Ok(res.rows_affected())
+}
+
+pub struct SessionService;
+
+impl SessionService {
+    pub async fn list_sessions(pool: &SqlitePool, session_token: String) -> Result<Vec<SessionItem>, AppError> {
+        let current = AuthService::require_session(pool, &session_token).await?;
+
+        let mut items = sqlx::query_as::<_, SessionItem>(
+            r#"
+            SELECT
+                id,
+                label,
+                created_at,
+                COALESCE(last_seen_at, created_at) AS last_seen_at,
+                expires_at
+            FROM sessions
+            WHERE user_id = ?1
+              AND revoked_at IS NULL
+              AND expires_at > ?2
+              AND ?2 - COALESCE(last_seen_at, created_at) < idle_timeout
+            ORDER BY last_seen_at DESC, id DESC
+            "#,
+        )
+        .bind(current.user_id)
+        .bind(now_unix())
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        for item in &mut items {
+            item.current = item.id == current.session_id;
+        }
+
+        Ok(items)
+    }
+
+    // Revoking the current session is the same as logging out.
+    pub async fn revoke_session(pool: &SqlitePool, session_token: String, session_id: i64) -> Result<(), AppError> {
+        let current = AuthService::require_session(pool, &session_token).await?;
+
+        let res = sqlx::query(
+            r#"
+            UPDATE sessions
+            SET revoked_at = ?3
+            WHERE id = ?1 AND user_id = ?2 AND revoked_at IS NULL
+            "#,
+        )
+        .bind(session_id)
+        .bind(current.user_id)
+        .bind(now_unix())
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Revoke session failed: {e}"))?;
+
+        if res.rows_affected() == 0 {
+            return Err(AppError::new("SESSION_NOT_FOUND", "Session not found."));
+        }
+
+        Ok(())
+    }
+
+    pub async fn revoke_all_other_sessions(pool: &SqlitePool, session_token: String) -> Result<u64, AppError> {
+        let current = AuthService::require_session(pool, &session_token).await?;
+
+        let mut conn = pool.acquire().await.map_err(|e| format!("DB error: {e}"))?;
+        revoke_other_sessions(&mut conn, current.user_id, current.session_id).await
+    }
+}
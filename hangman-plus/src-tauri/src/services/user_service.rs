@@ -0,0 +1,215 @@
+use sqlx::{SqliteConnection, SqlitePool};
+use time::OffsetDateTime;
+
+use crate::db::audit_log;
+use crate::domain::dto::UserAdminItem;
+use crate::domain::role::{Permission, Role};
+use crate::errors::AppError;
+use crate::services::auth_service::AuthService;
+
+fn now_unix() -> i64 {
+    OffsetDateTime::now_utc().unix_timestamp()
+}
+
+async fn user_by_id(conn: &mut SqliteConnection, user_id: i64) -> Result<Option<UserAdminItem>, AppError> {
+    sqlx::query_as::<_, UserAdminItem>(
+        r#"
+        SELECT u.id, u.username, u.first_name, u.last_name, u.email, u.role, u.created_at,
+               CASE WHEN la.locked_until > ?2 THEN la.locked_until END AS locked_until
+        FROM users u
+        LEFT JOIN login_attempts la ON la.user_id = u.id
+        WHERE u.id = ?1
+        "#,
+    )
+    .bind(user_id)
+    .bind(now_unix())
+    .fetch_optional(conn)
+    .await
+    .map_err(|e| format!("DB error: {e}").into())
+}
+
+pub struct UserService;
+
+impl UserService {
+    pub async fn list_users(pool: &SqlitePool, session_token: String) -> Result<Vec<UserAdminItem>, AppError> {
+        let (_user_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::UsersManage).await?;
+
+        sqlx::query_as::<_, UserAdminItem>(
+            r#"
+            SELECT u.id, u.username, u.first_name, u.last_name, u.email, u.role, u.created_at,
+                   CASE WHEN la.locked_until > ?1 THEN la.locked_until END AS locked_until
+            FROM users u
+            LEFT JOIN login_attempts la ON la.user_id = u.id
+            ORDER BY u.username COLLATE NOCASE
+            "#,
+        )
+        .bind(now_unix())
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("DB error: {e}").into())
+    }
+
+    pub async fn set_user_role(
+        pool: &SqlitePool,
+        session_token: String,
+        user_id: i64,
+        role: String,
+    ) -> Result<UserAdminItem, AppError> {
+        let (actor_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::UsersManage).await?;
+
+        let Some(new_role) = Role::parse(&role) else {
+            return Err(AppError::new("ROLE_INVALID", "Role must be ADMIN, MODERATOR or USER."));
+        };
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        let before = user_by_id(&mut tx, user_id)
+            .await?
+            .ok_or_else(|| AppError::new("USER_NOT_FOUND", "User not found."))?;
+
+        if before.role == new_role.as_str() {
+            return Ok(before);
+        }
+
+        // someone has to be able to manage roles afterwards
+        if before.role == Role::Admin.as_str() {
+            let admins: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM users WHERE role = ?1")
+                .bind(Role::Admin.as_str())
+                .fetch_one(&mut *tx)
+                .await
+                .map_err(|e| format!("DB error: {e}"))?;
+
+            if admins <= 1 {
+                return Err(AppError::new("LAST_ADMIN", "The last admin cannot be demoted."));
+            }
+        }
+
+        sqlx::query("UPDATE users SET role = ?1 WHERE id = ?2")
+            .bind(new_role.as_str())
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("Role update failed: {e}"))?;
+
+        audit_log::record(
+            &mut tx,
+            actor_id,
+            "USER_ROLE_CHANGE",
+            "USER",
+            Some(user_id),
+            Some(serde_json::json!({ "role": before.role })),
+            Some(serde_json::json!({ "role": new_role.as_str() })),
+        )
+        .await?;
+
+        let after = user_by_id(&mut tx, user_id)
+            .await?
+            .ok_or_else(|| AppError::new("USER_NOT_FOUND", "User not found."))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Role update commit failed: {e}"))?;
+
+        Ok(after)
+    }
+
+    // Clears failed-login tracking (lockout and backoff) for one account.
+    pub async fn unlock_account(
+        pool: &SqlitePool,
+        session_token: String,
+        user_id: i64,
+    ) -> Result<UserAdminItem, AppError> {
+        let (actor_id, _role) =
+            AuthService::require_permission(pool, &session_token, Permission::UsersManage).await?;
+
+        let mut tx = pool
+            .begin()
+            .await
+            .map_err(|e| format!("Begin transaction failed: {e}"))?;
+
+        user_by_id(&mut tx, user_id)
+            .await?
+            .ok_or_else(|| AppError::new("USER_NOT_FOUND", "User not found."))?;
+
+        let before = sqlx::query_as::<_, (i64, Option<i64>)>(
+            r#"SELECT failed_count, locked_until FROM login_attempts WHERE user_id = ?1"#,
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| format!("DB error: {e}"))?;
+
+        if let Some((failed_count, locked_until)) = before {
+            sqlx::query(r#"DELETE FROM login_attempts WHERE user_id = ?1"#)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| format!("Unlock failed: {e}"))?;
+
+            audit_log::record(
+                &mut tx,
+                actor_id,
+                "USER_UNLOCK",
+                "USER",
+                Some(user_id),
+                Some(serde_json::json!({ "failedCount": failed_count, "lockedUntil": locked_until })),
+                None,
+            )
+            .await?;
+        }
+
+        let after = user_by_id(&mut tx, user_id)
+            .await?
+            .ok_or_else(|| AppError::new("USER_NOT_FOUND", "User not found."))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| format!("Unlock commit failed: {e}"))?;
+
+        Ok(after)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{AppConfig, LoginThrottle};
+    use crate::db::test_util::{login_as, temp_pool};
+    use crate::services::auth_service::ACCOUNT_LOCKED;
+
+    #[tokio::test]
+    async fn unlock_clears_the_lockout() {
+        let (_dir, pool) = temp_pool().await;
+        let admin = login_as(&pool, "admin", "ADMIN").await;
+        login_as(&pool, "student", "USER").await;
+
+        let config = AppConfig {
+            login_throttle: LoginThrottle {
+                free_attempts: 100,
+                lockout_threshold: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let login = |password: &'static str| {
+            AuthService::login_user(&pool, "student".into(), password.into(), None, &config)
+        };
+
+        let err = login("wrong-password").await.err().unwrap();
+        assert_eq!(err.code, ACCOUNT_LOCKED);
+
+        let student_id: i64 = sqlx::query_scalar("SELECT id FROM users WHERE username = 'student'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let item = UserService::unlock_account(&pool, admin, student_id).await.unwrap();
+        assert!(item.locked_until.is_none());
+
+        login("password1").await.unwrap();
+    }
+}
@@ -0,0 +1,285 @@
+use std::fmt;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::errors::AppError;
+use crate::utils::security::sha256_hex;
+
+// Card images are stored the way the UI uses them ("/cards/bones/femur.png").
+// Every asset root is a folder that may contain such a `cards/` tree
+// (the frontend public folder, the app data folder, ...).
+const CARDS_PREFIX: &str = "/cards/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImagePathError {
+    Empty,
+    NotAllowed,
+    Traversal,
+    NotFound,
+}
+
+impl ImagePathError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ImagePathError::Empty => "IMAGE_PATH_EMPTY",
+            ImagePathError::NotAllowed => "IMAGE_PATH_NOT_ALLOWED",
+            ImagePathError::Traversal => "IMAGE_PATH_TRAVERSAL",
+            ImagePathError::NotFound => "IMAGE_NOT_FOUND",
+        }
+    }
+}
+
+impl fmt::Display for ImagePathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ImagePathError::Empty => "Image path is required.",
+            ImagePathError::NotAllowed => "Image path must start with /cards/.",
+            ImagePathError::Traversal => "Image path must stay inside the cards folder.",
+            ImagePathError::NotFound => "Image file does not exist.",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl From<ImagePathError> for AppError {
+    fn from(e: ImagePathError) -> Self {
+        AppError::new(e.code(), e.to_string())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AssetRoots {
+    roots: Vec<PathBuf>,
+    writable: Option<PathBuf>, // where imported images are copied to
+}
+
+impl AssetRoots {
+    pub fn new(candidates: Vec<PathBuf>) -> Self {
+        // canonical roots so the starts_with check below can't be fooled by `..` or symlinks
+        let roots = candidates
+            .into_iter()
+            .filter_map(|p| p.canonicalize().ok())
+            .collect();
+
+        Self {
+            roots,
+            writable: None,
+        }
+    }
+
+    // Adds a root the app can copy images into (app data folder).
+    pub fn with_writable_root(mut self, dir: PathBuf) -> Self {
+        if fs::create_dir_all(dir.join("cards")).is_ok() {
+            if let Ok(dir) = dir.canonicalize() {
+                self.roots.push(dir.clone());
+                self.writable = Some(dir);
+            }
+        }
+        self
+    }
+
+    // Path a stored image gets: /cards/<folder>/<sha256>.<ext>. Naming by content
+    // means two different files can never end up behind the same path.
+    pub fn content_image_path(folder: &str, bytes: &[u8], ext: &str) -> Result<String, String> {
+        let image_path = format!("{CARDS_PREFIX}{folder}/{}.{ext}", sha256_hex(bytes));
+        clean_image_path(&image_path).map_err(|e| e.to_string())
+    }
+
+    // Writes the bytes behind `image_path` (from content_image_path) in the writable
+    // root. Returns the file if this call created it, so the caller can undo it.
+    pub fn store_image(&self, image_path: &str, bytes: &[u8]) -> Result<Option<PathBuf>, String> {
+        let root = self
+            .writable
+            .as_ref()
+            .ok_or("No writable asset folder configured.")?;
+
+        let cleaned = clean_image_path(image_path).map_err(|e| e.to_string())?;
+        let target = root.join(cleaned.trim_start_matches('/'));
+
+        // same name = same content, nothing to do
+        if target.exists() {
+            return Ok(None);
+        }
+
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("Create image folder failed: {e}"))?;
+        }
+        fs::write(&target, bytes).map_err(|e| format!("Write image failed: {e}"))?;
+
+        Ok(Some(target))
+    }
+
+    // Image bytes and content type for the card image protocol. `uri_path` is the
+    // request path, i.e. the stored image path percent-encoded by convertFileSrc.
+    pub fn serve(&self, uri_path: &str) -> Result<(Vec<u8>, &'static str), ImagePathError> {
+        let decoded = percent_decode(uri_path.trim_start_matches('/'));
+        let image_path = if decoded.starts_with('/') {
+            decoded
+        } else {
+            format!("/{decoded}")
+        };
+
+        let file = self.resolve(&image_path)?;
+        let bytes = fs::read(&file).map_err(|_| ImagePathError::NotFound)?;
+
+        let mime = match file.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("png") => "image/png",
+            Some("jpg" | "jpeg") => "image/jpeg",
+            Some("gif") => "image/gif",
+            Some("webp") => "image/webp",
+            _ => "application/octet-stream",
+        };
+
+        Ok((bytes, mime))
+    }
+
+    // Checks the path and returns the cleaned form that should be stored in the DB.
+    pub fn validate(&self, image_path: &str) -> Result<String, ImagePathError> {
+        let cleaned = clean_image_path(image_path)?;
+        self.resolve(&cleaned)?;
+        Ok(cleaned)
+    }
+
+    // Finds the file on disk for a stored image path.
+    pub fn resolve(&self, image_path: &str) -> Result<PathBuf, ImagePathError> {
+        let cleaned = clean_image_path(image_path)?;
+        let relative = cleaned.trim_start_matches('/');
+
+        let mut escaped = false;
+
+        for root in &self.roots {
+            let full = match root.join(relative).canonicalize() {
+                Ok(p) => p,
+                Err(_) => continue,
+            };
+
+            if !full.starts_with(root.join("cards")) {
+                escaped = true;
+                continue;
+            }
+
+            if full.is_file() {
+                return Ok(full);
+            }
+        }
+
+        if escaped {
+            Err(ImagePathError::Traversal)
+        } else {
+            Err(ImagePathError::NotFound)
+        }
+    }
+}
+
+// "%2Fcards%2Ffemur.png" -> "/cards/femur.png"; bad escapes are kept as is
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn clean_image_path(raw: &str) -> Result<String, ImagePathError> {
+    let path = raw.trim().replace('\\', "/");
+
+    if path.is_empty() {
+        return Err(ImagePathError::Empty);
+    }
+    if !path.starts_with(CARDS_PREFIX) {
+        return Err(ImagePathError::NotAllowed);
+    }
+
+    let has_dots = Path::new(&path)
+        .components()
+        .any(|c| matches!(c, Component::ParentDir | Component::CurDir));
+
+    if has_dots || path.contains('\0') {
+        return Err(ImagePathError::Traversal);
+    }
+
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn roots_with_femur() -> (tempfile::TempDir, AssetRoots) {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("cards/bones")).unwrap();
+        fs::write(dir.path().join("cards/bones/femur.png"), b"png").unwrap();
+        fs::write(dir.path().join("secret.txt"), b"secret").unwrap();
+
+        let roots = AssetRoots::new(vec![dir.path().to_path_buf()]);
+        (dir, roots)
+    }
+
+    #[test]
+    fn accepts_existing_card_image() {
+        let (_dir, roots) = roots_with_femur();
+        assert_eq!(
+            roots.validate("  /cards/bones/femur.png ").unwrap(),
+            "/cards/bones/femur.png"
+        );
+    }
+
+    #[test]
+    fn rejects_traversal_and_missing_files() {
+        let (_dir, roots) = roots_with_femur();
+        assert_eq!(roots.validate(""), Err(ImagePathError::Empty));
+        assert_eq!(roots.validate("/secret.txt"), Err(ImagePathError::NotAllowed));
+        assert_eq!(
+            roots.validate("/cards/../secret.txt"),
+            Err(ImagePathError::Traversal)
+        );
+        assert_eq!(
+            roots.validate("/cards/bones/tibia.png"),
+            Err(ImagePathError::NotFound)
+        );
+    }
+
+    #[test]
+    fn serves_encoded_paths_but_not_traversal() {
+        let (_dir, roots) = roots_with_femur();
+        let (bytes, mime) = roots.serve("/%2Fcards%2Fbones%2Ffemur.png").unwrap();
+        assert_eq!((bytes.as_slice(), mime), (&b"png"[..], "image/png"));
+        assert_eq!(
+            roots.serve("/%2Fcards%2F..%2Fsecret.txt").unwrap_err(),
+            ImagePathError::Traversal
+        );
+    }
+
+    #[test]
+    fn stored_images_are_named_by_content() {
+        let dir = tempfile::tempdir().unwrap();
+        let roots = AssetRoots::new(vec![]).with_writable_root(dir.path().to_path_buf());
+
+        let a = AssetRoots::content_image_path("packs", b"one femur", "png").unwrap();
+        let b = AssetRoots::content_image_path("packs", b"another femur", "png").unwrap();
+        assert_ne!(a, b);
+
+        assert!(roots.store_image(&a, b"one femur").unwrap().is_some());
+        assert!(roots.store_image(&a, b"one femur").unwrap().is_none());
+        assert_eq!(roots.resolve(&a).map(|p| fs::read(p).unwrap()).unwrap(), b"one femur");
+    }
+}
@@ -1 +1,3 @@
+pub mod assets;
+pub mod paging;
 pub mod security;
@@ -0,0 +1,31 @@
+// Page number and size for the admin lists (cards, analytics, audit log)
+
+pub const DEFAULT_PAGE_SIZE: i64 = 50;
+pub const MAX_PAGE_SIZE: i64 = 200;
+// highest page whose OFFSET still fits in an i64; anything past it is empty anyway
+const MAX_PAGE: i64 = i64::MAX / MAX_PAGE_SIZE;
+
+// (page, page_size) with defaults and the bounds applied
+pub fn page_bounds(page: Option<i64>, page_size: Option<i64>) -> (i64, i64) {
+    let page = page.unwrap_or(1).clamp(1, MAX_PAGE);
+    let page_size = page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    (page, page_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_keep_the_offset_in_range() {
+        assert_eq!(page_bounds(None, None), (1, DEFAULT_PAGE_SIZE));
+        assert_eq!(page_bounds(Some(0), Some(0)), (1, 1));
+        assert_eq!(page_bounds(Some(-5), Some(-5)), (1, 1));
+
+        let (page, page_size) = page_bounds(Some(i64::MAX), Some(i64::MAX));
+        assert_eq!(page_size, MAX_PAGE_SIZE);
+        assert!((page - 1).checked_mul(page_size).is_some());
+    }
+}
@@ -2,7 +2,8 @@ use argon2::{
     password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
     Argon2,
 };
-use rand::rngs::OsRng;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use rand::{rngs::OsRng, RngCore};
 use sha2::{Digest, Sha256};
 
 pub fn hash_password(password: &str) -> Result<String, String> {
@@ -21,9 +22,104 @@ pub fn verify_password(password: &str, hash: &str) -> Result<bool, String> {
         .is_ok())
 }
 
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hex::encode(hasher.finalize())
+}
+
 // We store only token hash in DB (safer than storing raw token)
 pub fn hash_token(raw_token: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(raw_token.as_bytes());
     hex::encode(hasher.finalize())
 }
+
+// ---------- ed25519 (content pack signatures) ----------
+// Keys and signatures travel as hex strings.
+
+// Returns (secret_key, public_key)
+pub fn ed25519_generate() -> (String, String) {
+    let mut seed = [0u8; 32];
+    OsRng.fill_bytes(&mut seed);
+    let key = SigningKey::from_bytes(&seed);
+    (hex::encode(seed), hex::encode(key.verifying_key().to_bytes()))
+}
+
+// Returns (public_key, signature)
+pub fn ed25519_sign(secret_key: &str, message: &[u8]) -> Result<(String, String), String> {
+    let seed: [u8; 32] = hex::decode(secret_key.trim())
+        .ok()
+        .and_then(|b| b.try_into().ok())
+        .ok_or("Signing key must be 32 bytes in hex.")?;
+
+    let key = SigningKey::from_bytes(&seed);
+    let signature = key.sign(message);
+
+    Ok((
+        hex::encode(key.verifying_key().to_bytes()),
+        hex::encode(signature.to_bytes()),
+    ))
+}
+
+// Strict verification: rejects weak (small order) keys and non-canonical
+// signatures, which plain `verify` lets through.
+pub fn ed25519_verify(public_key: &str, signature: &str, message: &[u8]) -> bool {
+    let key = hex::decode(public_key.trim())
+        .ok()
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+        .and_then(|b| VerifyingKey::from_bytes(&b).ok());
+
+    let signature = hex::decode(signature.trim())
+        .ok()
+        .and_then(|b| <[u8; 64]>::try_from(b).ok())
+        .map(|b| Signature::from_bytes(&b));
+
+    match (key, signature) {
+        (Some(key), Some(signature)) => key.verify_strict(message, &signature).is_ok(),
+        _ => false,
+    }
+}
+
+pub fn is_ed25519_public_key(public_key: &str) -> bool {
+    hex::decode(public_key.trim())
+        .ok()
+        .and_then(|b| <[u8; 32]>::try_from(b).ok())
+        .and_then(|b| VerifyingKey::from_bytes(&b).ok())
+        .is_some_and(|key| !key.is_weak())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ed25519_round_trip() {
+        let (secret, public) = ed25519_generate();
+        let manifest = br#"{"version":1,"files":[]}"#;
+
+        let (signed_by, signature) = ed25519_sign(&secret, manifest).unwrap();
+        assert_eq!(signed_by, public);
+        assert!(is_ed25519_public_key(&public));
+        assert!(ed25519_verify(&public, &signature, manifest));
+
+        // tampered manifest
+        assert!(!ed25519_verify(&public, &signature, br#"{"version":2,"files":[]}"#));
+
+        // wrong key
+        let (_, other) = ed25519_generate();
+        assert!(!ed25519_verify(&other, &signature, manifest));
+
+        // garbage in, false out
+        assert!(!ed25519_verify("zz", &signature, manifest));
+        assert!(!ed25519_verify(&public, "00", manifest));
+    }
+
+    #[test]
+    fn weak_keys_are_not_publisher_keys() {
+        // the identity point: a valid encoding, but of a small order key
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert!(!is_ed25519_public_key(&hex::encode(identity)));
+    }
+}
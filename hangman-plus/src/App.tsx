@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { safeInvoke } from "./lib/invoke";
+import { SESSION_ENDED_EVENT, safeInvoke } from "./lib/invoke";
 
 import LandingPage from "./pages/LandingPage";
 import HomePage from "./pages/HomePage";
@@ -29,8 +29,12 @@ type GameSettings = {
   language: "EN" | "LAT";
   difficulty: "EASY" | "HARD";
   maxWrong: number;
+  cardDifficulty: CardDifficulty | null; // computed card difficulty, null = any
+  opponent: CardDifficulty | null; // bot level racing each card, null = solo
 };
 
+type CardDifficulty = "EASY" | "MEDIUM" | "HARD";
+
 const SESSION_KEY = "hangman_session_token";
 
 export default function App() {
@@ -45,8 +49,8 @@ export default function App() {
 
   const [sessionToken, setSessionToken] = useState<string | null>(null);
   const [role, setRole] = useState<Role | null>(null);
+  const [permissions, setPermissions] = useState<string[]>([]);
   const [username, setUsername] = useState<string | null>(null);
-  const [userId, setUserId] = useState<number | null>(null);
 
   const showToast = (msg: string, ms = 2500) => {
     setToastMsg(msg);
@@ -62,8 +66,8 @@ export default function App() {
   const loadMe = async (token: string) => {
     const me = await safeInvoke<MeResponse>("get_me", { sessionToken: token });
     setRole(me.role);
+    setPermissions(me.permissions);
     setUsername(me.username);
-    setUserId(me.id);
   };
 
   useEffect(() => {
@@ -78,13 +82,33 @@ export default function App() {
         localStorage.removeItem(SESSION_KEY);
         setSessionToken(null);
         setRole(null);
+        setPermissions([]);
         setUsername(null);
-        setUserId(null);
         setScreen("landing");
       });
     // eslint-disable-next-line react-hooks/exhaustive-deps
   }, []);
 
+  // any command rejecting the token (expired / revoked) sends the user back to login
+  useEffect(() => {
+    const onSessionEnded = (ev: Event) => {
+      const code = (ev as CustomEvent).detail?.code;
+
+      localStorage.removeItem(SESSION_KEY);
+      setSessionToken(null);
+      setRole(null);
+      setPermissions([]);
+      setUsername(null);
+      setGameSettings(null);
+      setScreen("landing");
+      setLoginOpen(true);
+      showToast(code === "SESSION_EXPIRED" ? "Session expired. Please log in again." : "You were logged out.");
+    };
+
+    window.addEventListener(SESSION_ENDED_EVENT, onSessionEnded);
+    return () => window.removeEventListener(SESSION_ENDED_EVENT, onSessionEnded);
+  }, []);
+
   const onLoginSuccess = async (token: string) => {
     localStorage.setItem(SESSION_KEY, token);
     setSessionToken(token);
@@ -98,8 +122,8 @@ export default function App() {
       localStorage.removeItem(SESSION_KEY);
       setSessionToken(null);
       setRole(null);
+      setPermissions([]);
       setUsername(null);
-      setUserId(null);
       setScreen("landing");
       showToast(e?.message ?? "Failed to load profile.");
     }
@@ -118,8 +142,8 @@ export default function App() {
       localStorage.removeItem(SESSION_KEY);
       setSessionToken(null);
       setRole(null);
+      setPermissions([]);
       setUsername(null);
-      setUserId(null);
       setScreen("landing");
       showToast(e?.message ?? "Failed to load profile.");
     }
@@ -149,8 +173,8 @@ export default function App() {
     localStorage.removeItem(SESSION_KEY);
     setSessionToken(null);
     setRole(null);
+    setPermissions([]);
     setUsername(null);
-    setUserId(null);
 
     setGameSettings(null);
     setScreen("landing");
@@ -166,6 +190,7 @@ export default function App() {
       {screen === "home" && (
         <HomePage
           role={role ?? undefined}
+          permissions={permissions}
           onLogout={onLogout}
           onAddNewCard={() => setScreen("add_card")}
           onEditCards={() => setScreen("edit_cards")}
@@ -179,10 +204,9 @@ export default function App() {
         />
       )}
 
-      {screen === "stats" && sessionToken && userId != null && (
+      {screen === "stats" && sessionToken && (
         <StatsPage
            sessionToken={sessionToken}
-           userId={userId}
            onBack={() => setScreen("home")}
         />
     )}
@@ -191,7 +215,7 @@ export default function App() {
       {screen === "add_card" && sessionToken && (
         <AddNewCardPage
           sessionToken={sessionToken}
-          mode={(role ?? "USER") as "ADMIN" | "USER"}
+          mode={permissions.includes("cards.edit") ? "ADMIN" : "USER"}
           onBack={() => setScreen("home")}
         />
       )}
@@ -204,8 +228,12 @@ export default function App() {
         <CardRequestsPage sessionToken={sessionToken} onBack={() => setScreen("home")} />
       )}
 
-      {screen === "game" && gameSettings && userId != null && (
-        <GamePage settings={gameSettings} userId={userId} onExit={onExitGame} />
+      {screen === "game" && gameSettings && sessionToken && (
+        <GamePage
+          settings={gameSettings}
+          sessionToken={sessionToken}
+          onExit={onExitGame}
+        />
       )}
 
       <LoginModal
@@ -1,4 +1,5 @@
 import { useState } from "react";
+import { deviceLabel } from "../lib/device";
 import { safeInvoke } from "../lib/invoke";
 import "../styles/AuthModal.css";
 import type { LoginResponse } from "../types/auth";
@@ -32,6 +33,7 @@ export default function LoginModal({
       const res = await safeInvoke<LoginResponse>("login_user", {
         identifier,
         password,
+        deviceLabel: deviceLabel(),
       });
       onSuccess(res.session_token);
     } catch (e: any) {
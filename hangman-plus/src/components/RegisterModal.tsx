@@ -1,4 +1,5 @@
 import { useState } from "react";
+import { deviceLabel } from "../lib/device";
 import { safeInvoke } from "../lib/invoke";
 import "../styles/AuthModal.css";
 
@@ -37,6 +38,7 @@ export default function RegisterModal({ open, onClose, onSuccess, onSwitchToLogi
       const res = await safeInvoke<{ session_token: string }>("login_user", {
         identifier: username,
         password,
+        deviceLabel: deviceLabel(),
       });
 
       onSuccess(res.session_token);
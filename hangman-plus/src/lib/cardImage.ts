@@ -0,0 +1,8 @@
+import { convertFileSrc } from "@tauri-apps/api/core";
+
+// Card images are stored as "/cards/..." paths. Imported ones live in app data,
+// which the webview cannot load, so every card image goes through the app's
+// "cardimg" protocol, which looks in the bundled cards and in app data.
+export function cardImageSrc(imagePath: string): string {
+  return convertFileSrc(imagePath.trim(), "cardimg");
+}
@@ -0,0 +1,14 @@
+// Short, human readable name for this machine, shown in the session list.
+export function deviceLabel(): string {
+  const ua = navigator.userAgent;
+
+  const os = /Windows/i.test(ua)
+    ? "Windows"
+    : /Mac OS X|Macintosh/i.test(ua)
+    ? "macOS"
+    : /Linux/i.test(ua)
+    ? "Linux"
+    : "Unknown OS";
+
+  return `Hangman+ desktop • ${os}`;
+}
@@ -1,5 +1,10 @@
 import { invoke, type InvokeArgs } from "@tauri-apps/api/core";
 
+// Fired when the backend rejects the session token (expired, revoked, unknown).
+export const SESSION_ENDED_EVENT = "hangman:session-ended";
+
+const SESSION_ERROR_CODES = ["SESSION_EXPIRED", "SESSION_INVALID"];
+
 export async function safeInvoke<T>(cmd: string, args?: InvokeArgs): Promise<T> {
   const w = window as any;
 
@@ -7,5 +12,12 @@ export async function safeInvoke<T>(cmd: string, args?: InvokeArgs): Promise<T>
     throw new Error("This action requires the Hangman+ desktop app (Tauri).");
   }
 
-  return invoke<T>(cmd, args);
+  try {
+    return await invoke<T>(cmd, args);
+  } catch (e: any) {
+    if (SESSION_ERROR_CODES.includes(e?.code)) {
+      window.dispatchEvent(new CustomEvent(SESSION_ENDED_EVENT, { detail: e }));
+    }
+    throw e;
+  }
 }
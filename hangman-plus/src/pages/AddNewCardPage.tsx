@@ -10,6 +10,8 @@ type Props = {
 
 type Category = "ORGANS" | "BONES";
 
+type FieldError = { field: string; code: string; message: string };
+
 export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
   const isAdmin = mode === "ADMIN";
 
@@ -19,6 +21,7 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
   const [imagePath, setImagePath] = useState("/cards/bones/");
   const [loading, setLoading] = useState(false);
   const [msg, setMsg] = useState<string>("");
+  const [fieldErrors, setFieldErrors] = useState<Record<string, string>>({});
 
   const canSubmit = useMemo(() => {
     return (
@@ -37,12 +40,17 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
     }
 
     setMsg("");
+    setFieldErrors({});
     setLoading(true);
 
     try {
       const command = isAdmin ? "admin_add_card" : "user_request_card";
 
-      const res = await safeInvoke<{ id: number; status: string }>(command, {
+      const res = await safeInvoke<{
+        id: number;
+        status: string;
+        duplicates: { cardId: number; english: string; latin: string; reason: string }[];
+      }>(command, {
         input: {
           sessionToken,
           category,
@@ -52,16 +60,26 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
         },
       });
 
+      const dupes = res.duplicates ?? [];
+      const dupeNote =
+        dupes.length > 0
+          ? ` ⚠️ Possible duplicate of: ${dupes
+              .map((d) => `#${d.cardId} ${d.english} / ${d.latin}`)
+              .join(", ")}`
+          : "";
+
       if (isAdmin) {
-        setMsg(`✅ Card successfully saved! (Status: ${res.status})`);
+        setMsg(`✅ Card successfully saved! (Status: ${res.status})${dupeNote}`);
       } else {
-        setMsg("✅ Request successfully sent! An admin will review it.");
+        setMsg(`✅ Request successfully sent! An admin will review it.${dupeNote}`);
       }
 
       setEnglish("");
       setLatin("");
       setImagePath(category === "BONES" ? "/cards/bones/" : "/cards/organs/");
     } catch (e: any) {
+      const errs: FieldError[] = e?.fieldErrors ?? [];
+      setFieldErrors(Object.fromEntries(errs.map((f) => [f.field, f.message])));
       setMsg(`❌ ${e?.message ?? String(e)}`);
     } finally {
       setLoading(false);
@@ -136,6 +154,7 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
                 onChange={(e) => setEnglish(e.target.value)}
                 placeholder="e.g. Frontal bone"
               />
+              <FieldHint error={fieldErrors.english} />
             </div>
 
             <div>
@@ -148,6 +167,7 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
                 onChange={(e) => setLatin(e.target.value)}
                 placeholder="e.g. Os frontale"
               />
+              <FieldHint error={fieldErrors.latin} />
             </div>
           </div>
         </section>
@@ -164,6 +184,7 @@ export default function AddNewCardPage({ sessionToken, mode, onBack }: Props) {
             onChange={(e) => setImagePath(e.target.value)}
             placeholder="/cards/bones/frontal-bone.png"
           />
+          <FieldHint error={fieldErrors.imagePath} />
 
           <div className="hp-hint" style={{ marginTop: 8 }}>
             Currently we store only the image path. File upload can be added later.
@@ -214,3 +235,12 @@ function ChoiceMini({
     </button>
   );
 }
+
+function FieldHint({ error }: { error?: string }) {
+  if (!error) return null;
+  return (
+    <div className="hp-hint" style={{ marginTop: 6, color: "#ff8a8a" }}>
+      {error}
+    </div>
+  );
+}
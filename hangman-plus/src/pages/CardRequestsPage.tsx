@@ -1,5 +1,6 @@
 import { useEffect, useState } from "react";
 import { safeInvoke } from "../lib/invoke";
+import { cardImageSrc } from "../lib/cardImage";
 import "../styles/homepage.css";
 
 type Props = {
@@ -16,21 +17,48 @@ type PendingCard = {
   status: string;
   created_by: number;
   created_at: number;
+  review_comment: string | null;
+  reviewed_at: number | null;
+  resubmit_count: number;
+  possible_duplicates: { cardId: number; english: string; latin: string; reason: string }[] | null;
 };
 
+type Page<T> = {
+  items: T[];
+  total: number;
+  page: number;
+  pageSize: number;
+};
+
+const PAGE_SIZE = 25;
+
 export default function CardRequestsPage({ sessionToken, onBack }: Props) {
   const [items, setItems] = useState<PendingCard[]>([]);
   const [loading, setLoading] = useState(false);
   const [msg, setMsg] = useState<string>("");
+  const [comments, setComments] = useState<Record<number, string>>({});
+  const [selected, setSelected] = useState<number[]>([]);
+  const [batchComment, setBatchComment] = useState("");
+  const [search, setSearch] = useState("");
+  const [oldestFirst, setOldestFirst] = useState(false);
+  const [page, setPage] = useState(1);
+  const [total, setTotal] = useState(0);
 
   const load = async () => {
     setMsg("");
     setLoading(true);
     try {
-      const res = await safeInvoke<PendingCard[]>("list_pending_cards", {
+      const res = await safeInvoke<Page<PendingCard>>("list_pending_cards", {
         sessionToken,
+        query: {
+          search: search.trim() || null,
+          sort: oldestFirst ? "OLDEST" : "NEWEST",
+          page,
+          pageSize: PAGE_SIZE,
+        },
       });
-      setItems(res);
+      setItems(res.items);
+      setTotal(res.total);
     } catch (e: any) {
       setMsg(e?.message ?? String(e));
     } finally {
@@ -41,13 +69,24 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
   useEffect(() => {
     load();
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, []);
+  }, [search, oldestFirst, page]);
+
+  const pageCount = Math.max(1, Math.ceil(total / PAGE_SIZE));
+
+  const dropReviewed = (ids: number[]) => {
+    setItems((prev) => prev.filter((x) => !ids.includes(x.id)));
+    setTotal((t) => Math.max(0, t - ids.length));
+  };
 
   const approve = async (cardId: number) => {
     setMsg("");
     try {
-      await safeInvoke<void>("approve_card", { sessionToken, cardId });
-      setItems((prev) => prev.filter((x) => x.id !== cardId));
+      await safeInvoke<void>("approve_card", {
+        sessionToken,
+        cardId,
+        comment: comments[cardId] || null,
+      });
+      dropReviewed([cardId]);
     } catch (e: any) {
       setMsg(e?.message ?? String(e));
     }
@@ -56,8 +95,38 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
   const reject = async (cardId: number) => {
     setMsg("");
     try {
-      await safeInvoke<void>("reject_card", { sessionToken, cardId });
-      setItems((prev) => prev.filter((x) => x.id !== cardId));
+      await safeInvoke<void>("reject_card", {
+        sessionToken,
+        cardId,
+        comment: comments[cardId] || null,
+      });
+      dropReviewed([cardId]);
+    } catch (e: any) {
+      setMsg(e?.message ?? String(e));
+    }
+  };
+
+  const toggle = (cardId: number) =>
+    setSelected((prev) =>
+      prev.includes(cardId) ? prev.filter((x) => x !== cardId) : [...prev, cardId]
+    );
+
+  const reviewSelected = async (command: "approve_cards" | "reject_cards") => {
+    if (selected.length === 0) return;
+    setMsg("");
+    try {
+      const res = await safeInvoke<{ cardId: number; ok: boolean; error: string | null }[]>(
+        command,
+        { sessionToken, cardIds: selected, comment: batchComment || null }
+      );
+      const done = res.filter((r) => r.ok).map((r) => r.cardId);
+      dropReviewed(done);
+      setSelected([]);
+
+      const failed = res.filter((r) => !r.ok);
+      if (failed.length > 0) {
+        setMsg(failed.map((r) => `#${r.cardId}: ${r.error}`).join(" • "));
+      }
     } catch (e: any) {
       setMsg(e?.message ?? String(e));
     }
@@ -73,7 +142,7 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
           </div>
 
           <div className="hp-actions">
-            <button className="hp-ghost" type="button" onClick={load} disabled={loading}>
+            <button className="hp-ghost" type="button" onClick={() => load()} disabled={loading}>
               {loading ? "Refreshing..." : "Refresh"}
             </button>
             <button className="hp-ghost" type="button" onClick={onBack}>
@@ -91,10 +160,61 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
         <section className="hp-section">
           <div className="hp-section-header">
             <span className="hp-section-title">
-              Pending ({items.length})
+              Pending ({total})
             </span>
           </div>
 
+          <div style={{ display: "flex", gap: 10, marginBottom: 12, alignItems: "center" }}>
+            <input
+              className="hp-input"
+              style={{ flex: 1 }}
+              placeholder="Search English, Latin or synonym"
+              value={search}
+              onChange={(e) => {
+                setSearch(e.target.value);
+                setPage(1);
+              }}
+            />
+            <button
+              className="hp-ghost"
+              type="button"
+              onClick={() => {
+                setOldestFirst((v) => !v);
+                setPage(1);
+              }}
+            >
+              {oldestFirst ? "Oldest first" : "Newest first"}
+            </button>
+          </div>
+
+          {items.length > 0 && (
+            <div style={{ display: "flex", gap: 10, marginBottom: 12, alignItems: "center" }}>
+              <input
+                className="hp-input"
+                style={{ flex: 1 }}
+                placeholder="Comment for all selected (optional)"
+                value={batchComment}
+                onChange={(e) => setBatchComment(e.target.value)}
+              />
+              <button
+                className="hp-ghost"
+                type="button"
+                disabled={selected.length === 0}
+                onClick={() => reviewSelected("approve_cards")}
+              >
+                ✅ Approve selected ({selected.length})
+              </button>
+              <button
+                className="hp-ghost"
+                type="button"
+                disabled={selected.length === 0}
+                onClick={() => reviewSelected("reject_cards")}
+              >
+                ❌ Reject selected
+              </button>
+            </div>
+          )}
+
           {items.length === 0 && !loading && (
             <div className="hp-hint">No pending requests.</div>
           )}
@@ -113,6 +233,12 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
                 <div style={{ display: "flex", justifyContent: "space-between", gap: 12 }}>
                   <div>
                     <div style={{ fontSize: 16, fontWeight: 700 }}>
+                      <input
+                        type="checkbox"
+                        checked={selected.includes(c.id)}
+                        onChange={() => toggle(c.id)}
+                        style={{ marginRight: 8 }}
+                      />
                       #{c.id} • {c.category}
                     </div>
                     <div style={{ opacity: 0.9, marginTop: 4 }}>
@@ -121,6 +247,20 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
                     <div style={{ opacity: 0.75, marginTop: 6, fontSize: 13 }}>
                       <b>Image:</b> {c.image_path}
                     </div>
+                    {c.possible_duplicates && c.possible_duplicates.length > 0 && (
+                      <div style={{ marginTop: 6, fontSize: 13, color: "#f5c26b" }}>
+                        ⚠️ Possible duplicate of{" "}
+                        {c.possible_duplicates
+                          .map((d) => `#${d.cardId} ${d.english} / ${d.latin} (${d.reason})`)
+                          .join(", ")}
+                      </div>
+                    )}
+                    {c.resubmit_count > 0 && (
+                      <div style={{ opacity: 0.75, marginTop: 6, fontSize: 13 }}>
+                        <b>Resubmitted {c.resubmit_count}×.</b>
+                        {c.review_comment && <> Last review: “{c.review_comment}”</>}
+                      </div>
+                    )}
                   </div>
 
                   <div style={{ display: "flex", gap: 10, alignItems: "flex-start" }}>
@@ -133,10 +273,20 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
                   </div>
                 </div>
 
+                <input
+                  className="hp-input"
+                  style={{ marginTop: 10, width: "100%" }}
+                  placeholder="Comment for the submitter (optional)"
+                  value={comments[c.id] ?? ""}
+                  onChange={(e) =>
+                    setComments((prev) => ({ ...prev, [c.id]: e.target.value }))
+                  }
+                />
+
                 {/* optional preview */}
                 <div style={{ marginTop: 12, opacity: 0.9 }}>
                   <img
-                    src={c.image_path}
+                    src={cardImageSrc(c.image_path)}
                     alt={c.english}
                     style={{
                       width: "100%",
@@ -153,6 +303,30 @@ export default function CardRequestsPage({ sessionToken, onBack }: Props) {
               </div>
             ))}
           </div>
+
+          {pageCount > 1 && (
+            <div style={{ display: "flex", gap: 10, marginTop: 12, alignItems: "center" }}>
+              <button
+                className="hp-ghost"
+                type="button"
+                disabled={loading || page <= 1}
+                onClick={() => setPage((p) => p - 1)}
+              >
+                ← Prev
+              </button>
+              <span className="hp-hint">
+                Page {page} / {pageCount}
+              </span>
+              <button
+                className="hp-ghost"
+                type="button"
+                disabled={loading || page >= pageCount}
+                onClick={() => setPage((p) => p + 1)}
+              >
+                Next →
+              </button>
+            </div>
+          )}
         </section>
       </div>
     </div>
@@ -1,5 +1,6 @@
-import { useEffect, useMemo, useState } from "react";
+import { useEffect, useState } from "react";
 import { safeInvoke } from "../lib/invoke";
+import { cardImageSrc } from "../lib/cardImage";
 import "../styles/editcards.css";
 
 type Props = {
@@ -10,7 +11,7 @@ type Props = {
 type CardStatus = "APPROVED" | "PENDING" | "REJECTED";
 type Category = "ORGANS" | "BONES";
 
-type SortMode = "NEWEST" | "OLDEST";
+type SortMode = "NEWEST" | "OLDEST" | "ENGLISH" | "LATIN";
 
 type StatusFilter = "ALL" | CardStatus;
 type CategoryFilter = "ALL" | Category;
@@ -21,9 +22,21 @@ type CardAdminItem = {
   english: string;
   latin: string;
   image_path: string;
+  region: unknown | null; // hotspot on a shared diagram, kept as-is on save
   status: string;   // "APPROVED" | "PENDING" | "REJECTED"
+  created_by: number;
+  created_at: number;
 };
 
+type Page<T> = {
+  items: T[];
+  total: number;
+  page: number;
+  pageSize: number;
+};
+
+const PAGE_SIZE = 50;
+
 type RowDraft = {
   english: string;
   latin: string;
@@ -43,6 +56,9 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
   const [categoryFilter, setCategoryFilter] = useState<CategoryFilter>("ALL");
   const [statusFilter, setStatusFilter] = useState<StatusFilter>("ALL");
   const [sortMode, setSortMode] = useState<SortMode>("NEWEST");
+  const [search, setSearch] = useState("");
+  const [page, setPage] = useState(1);
+  const [total, setTotal] = useState(0);
 
   const [confirmOpen, setConfirmOpen] = useState(false);
   const [confirmId, setConfirmId] = useState<number | null>(null);
@@ -50,9 +66,22 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
   const load = async () => {
     setLoading(true);
     try {
-      const rows = await safeInvoke<CardAdminItem[]>("list_all_cards_admin", { sessionToken });
+      // filtering, sorting and paging happen in the backend
+      const res = await safeInvoke<Page<CardAdminItem>>("list_all_cards_admin", {
+        sessionToken,
+        query: {
+          category: categoryFilter === "ALL" ? null : categoryFilter,
+          status: statusFilter === "ALL" ? null : statusFilter,
+          search: search.trim() || null,
+          sort: sortMode,
+          page,
+          pageSize: PAGE_SIZE,
+        },
+      });
+      const rows = res.items;
 
       setCards(rows);
+      setTotal(res.total);
 
       // init drafts for new cards only (keep user edits if already typed)
       setDrafts((prev) => {
@@ -79,22 +108,15 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
   useEffect(() => {
     load().catch(console.error);
     // eslint-disable-next-line react-hooks/exhaustive-deps
-  }, []);
+  }, [categoryFilter, statusFilter, sortMode, search, page]);
 
-  const filtered = useMemo(() => {
-    let list = [...cards];
-
-    if (categoryFilter !== "ALL") {
-      list = list.filter((c) => c.category === categoryFilter);
-    }
-
-    if (statusFilter !== "ALL") {
-      list = list.filter((c) => c.status === statusFilter);
-    }
+  // any filter change starts again from the first page
+  const changeFilter = <T,>(set: (v: T) => void) => (v: T) => {
+    set(v);
+    setPage(1);
+  };
 
-    list.sort((a, b) => (sortMode === "NEWEST" ? b.id - a.id : a.id - b.id));
-    return list;
-  }, [cards, categoryFilter, statusFilter, sortMode]);
+  const pageCount = Math.max(1, Math.ceil(total / PAGE_SIZE));
 
   const updateDraft = (id: number, patch: Partial<RowDraft>) => {
     setDrafts((prev) => {
@@ -131,6 +153,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
           english: d.english,
           latin: d.latin,
           imagePath: d.image_path,
+          region: cards.find((c) => c.id === id)?.region ?? null,
         },
       });
 
@@ -171,6 +194,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
 
       // remove locally
       setCards((prev) => prev.filter((c) => c.id !== id));
+      setTotal((t) => Math.max(0, t - 1));
       setDrafts((prev) => {
         const next = { ...prev };
         delete next[id];
@@ -207,7 +231,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
             <label>Category</label>
             <select
               value={categoryFilter}
-              onChange={(e) => setCategoryFilter(e.target.value as CategoryFilter)}
+              onChange={(e) => changeFilter(setCategoryFilter)(e.target.value as CategoryFilter)}
             >
               <option value="ALL">All</option>
               <option value="ORGANS">Organs</option>
@@ -219,7 +243,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
             <label>Status</label>
             <select
               value={statusFilter}
-              onChange={(e) => setStatusFilter(e.target.value as StatusFilter)}
+              onChange={(e) => changeFilter(setStatusFilter)(e.target.value as StatusFilter)}
             >
               <option value="ALL">All</option>
               <option value="APPROVED">Approved</option>
@@ -230,23 +254,37 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
 
           <div className="ec-control">
             <label>Sort</label>
-            <select value={sortMode} onChange={(e) => setSortMode(e.target.value as SortMode)}>
+            <select
+              value={sortMode}
+              onChange={(e) => changeFilter(setSortMode)(e.target.value as SortMode)}
+            >
               <option value="NEWEST">Newest</option>
               <option value="OLDEST">Oldest</option>
+              <option value="ENGLISH">English A–Z</option>
+              <option value="LATIN">Latin A–Z</option>
             </select>
           </div>
 
+          <div className="ec-control">
+            <label>Search</label>
+            <input
+              value={search}
+              placeholder="English, Latin or synonym"
+              onChange={(e) => changeFilter(setSearch)(e.target.value)}
+            />
+          </div>
+
           <button className="ec-ghost" onClick={() => load()} type="button" disabled={loading}>
             {loading ? "Loading..." : "Refresh"}
           </button>
 
           <div className="ec-count">
-            Showing <b>{filtered.length}</b> / {cards.length}
+            Showing <b>{cards.length}</b> / {total}
           </div>
         </div>
 
         <div className="ec-list">
-          {filtered.map((c) => {
+          {cards.map((c) => {
             const d = drafts[c.id];
             if (!d) return null;
 
@@ -258,7 +296,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
                 <div className="ec-thumb">
                   {d.image_path?.trim() ? (
                     <img
-                      src={d.image_path}
+                      src={cardImageSrc(d.image_path)}
                       alt="card"
                       onError={(e) => {
                         (e.currentTarget as HTMLImageElement).style.display = "none";
@@ -358,12 +396,36 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
             );
           })}
 
-          {!filtered.length && (
+          {!cards.length && (
             <div className="ec-empty">
               No cards match your filters.
             </div>
           )}
         </div>
+
+        {pageCount > 1 && (
+          <div className="ec-controls">
+            <button
+              className="ec-ghost"
+              onClick={() => setPage((p) => p - 1)}
+              type="button"
+              disabled={loading || page <= 1}
+            >
+              ← Prev
+            </button>
+            <div className="ec-count">
+              Page <b>{page}</b> / {pageCount}
+            </div>
+            <button
+              className="ec-ghost"
+              onClick={() => setPage((p) => p + 1)}
+              type="button"
+              disabled={loading || page >= pageCount}
+            >
+              Next →
+            </button>
+          </div>
+        )}
       </div>
 
       {confirmOpen && (
@@ -371,7 +433,7 @@ export default function EditCardsPage({ sessionToken, onBack }: Props) {
           <div className="ec-modal">
             <div className="ec-modal-title">Delete card?</div>
             <div className="ec-modal-text">
-              This will permanently remove the card from the database.
+              The card is archived: it leaves the games but stays in player stats and can be restored.
             </div>
             <div className="ec-modal-actions">
               <button className="ec-ghost" onClick={() => setConfirmOpen(false)} type="button">
@@ -1,5 +1,7 @@
 import { useEffect, useMemo, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { safeInvoke } from "../lib/invoke";
+import { cardImageSrc } from "../lib/cardImage";
 import "../styles/gamepage.css";
 
 type Settings = {
@@ -7,14 +9,24 @@ type Settings = {
   language: "EN" | "LAT";
   difficulty: "EASY" | "HARD";
   maxWrong: number;
+  cardDifficulty?: "EASY" | "MEDIUM" | "HARD" | null;
+  opponent?: "EASY" | "MEDIUM" | "HARD" | null;
 };
 
+type Point = { x: number; y: number };
+
+// Fractions of the image size (0..1)
+type Region =
+  | { kind: "BOX"; x: number; y: number; width: number; height: number }
+  | { kind: "POLYGON"; points: Point[] };
+
 type Card = {
   id: number;
   category: "ORGANS" | "BONES";
   english: string;
   latin: string;
   image_path: string;
+  region: Region | null;
 };
 
 type StartGameResponse = {
@@ -32,13 +44,21 @@ type NextCardResponse = {
   message: string;
 };
 
+type BotRoundResult = {
+  id: number;
+  botGuesses: string[];
+  botWrong: number;
+  botSolved: boolean;
+  outcome: "PLAYER" | "BOT" | "DRAW";
+};
+
 type Props = {
   settings: Settings;
-  userId: number;
+  sessionToken: string;
   onExit: () => void;
 };
 
-export default function GamePage({ settings, userId, onExit }: Props) {
+export default function GamePage({ settings, sessionToken, onExit }: Props) {
   const [gameId, setGameId] = useState<string | null>(null);
   const [card, setCard] = useState<Card | null>(null);
 
@@ -92,6 +112,7 @@ export default function GamePage({ settings, userId, onExit }: Props) {
       try {
         const res = await invoke<StartGameResponse>("start_game", {
           category: settings.category,
+          cardDifficulty: settings.cardDifficulty ?? null,
         });
 
         if (!mounted) return;
@@ -116,7 +137,7 @@ export default function GamePage({ settings, userId, onExit }: Props) {
     return () => {
       mounted = false;
     };
-  }, [settings.category]);
+  }, [settings.category, settings.cardDifficulty]);
 
   // Derive win/lose
   useEffect(() => {
@@ -145,7 +166,7 @@ export default function GamePage({ settings, userId, onExit }: Props) {
       try {
         await invoke("log_card_attempt", {
           req: {
-            userId,               
+            sessionToken,
             cardId: card.id,
             isWon: status === "won",
             category: settings.category,
@@ -153,16 +174,71 @@ export default function GamePage({ settings, userId, onExit }: Props) {
             difficulty: settings.difficulty,
             wrongCount: wrong,
             maxWrong: settings.maxWrong,
+            wrongLetters: guessed.filter((l) => !normalizedAnswer.includes(l)).join(""),
           },
         });
       } catch (e) {
         console.error("LOG_CARD_ATTEMPT failed:", e);
       }
+
+      if (!settings.opponent) return;
+
+      // the bot plays the same card; fewer wrong guesses wins the round
+      try {
+        const bot = await safeInvoke<BotRoundResult>("play_bot_round", {
+          input: {
+            sessionToken,
+            gameId,
+            cardId: card.id,
+            language: settings.language,
+            level: settings.opponent,
+            maxWrong: settings.maxWrong,
+            playerWrong: wrong,
+            playerSolved: status === "won",
+          },
+        });
+        const botLine = bot.botSolved
+          ? `🤖 Bot solved it with ${bot.botWrong} wrong (${bot.botGuesses.join("").toUpperCase()}).`
+          : `🤖 Bot got hanged (${bot.botGuesses.join("").toUpperCase()}).`;
+        const verdict =
+          bot.outcome === "PLAYER" ? "You win the round!" : bot.outcome === "BOT" ? "Bot wins the round." : "Draw.";
+        setUiMsg(`${botLine} ${verdict}`);
+      } catch (e) {
+        console.error("PLAY_BOT_ROUND failed:", e);
+        setUiMsg("Backend error: play_bot_round failed. Check console.");
+      }
     })();
-  }, [card, status, userId, wrong, settings.category, settings.language, settings.difficulty, settings.maxWrong]);
+  }, [card, status, sessionToken, gameId, wrong, guessed, normalizedAnswer, settings.category, settings.language, settings.difficulty, settings.maxWrong, settings.opponent]);
 
   const focusInput = () => inputRef.current?.focus();
 
+  // asks the backend solver which letter narrows the possible answers most
+  const suggestLetter = async () => {
+    if (status !== "playing") return;
+
+    try {
+      const res = await safeInvoke<{ suggestions: { letter: string; hitChance: number }[]; candidateCount: number }>(
+        "suggest_letter",
+        {
+          sessionToken,
+          category: settings.category,
+          language: settings.language,
+          pattern: revealed.map((ch) => (ch === "" ? "_" : ch)).join(""),
+          guessed,
+        }
+      );
+      const best = res.suggestions[0];
+      setUiMsg(
+        best
+          ? `💡 Try "${best.letter.toUpperCase()}" (${Math.round(best.hitChance * 100)}% of ${res.candidateCount} possible answers contain it).`
+          : "No hint available."
+      );
+    } catch (e) {
+      console.error("SUGGEST_LETTER failed:", e);
+      setUiMsg("Backend error: suggest_letter failed. Check console.");
+    }
+  };
+
   const submitLetter = (raw: string) => {
     if (!normalizedAnswer) return;
     if (status !== "playing") return;
@@ -251,7 +327,9 @@ export default function GamePage({ settings, userId, onExit }: Props) {
     }
   };
 
-  const headerLine = `${settings.category} • ${settings.language} • ${settings.difficulty} • ${mistakesLeft}/${settings.maxWrong}`;
+  const headerLine =
+    `${settings.category} • ${settings.language} • ${settings.difficulty} • ${mistakesLeft}/${settings.maxWrong}` +
+    (settings.opponent ? ` • vs ${settings.opponent} bot` : "");
 
   return (
     <div className="gp-page">
@@ -269,7 +347,14 @@ export default function GamePage({ settings, userId, onExit }: Props) {
 
         <div className="gp-layout">
           <div className="gp-hero">
-            {card?.image_path ? <img src={card.image_path} alt="card" /> : null}
+            {card?.image_path && card.region ? (
+              <div className="gp-diagram">
+                <img src={cardImageSrc(card.image_path)} alt="diagram" />
+                <RegionOverlay region={card.region} />
+              </div>
+            ) : card?.image_path ? (
+              <img src={cardImageSrc(card.image_path)} alt="card" />
+            ) : null}
           </div>
 
           <div className="gp-grid">
@@ -328,6 +413,14 @@ export default function GamePage({ settings, userId, onExit }: Props) {
                   <button className="gp-secondary" type="button" onClick={focusInput}>
                     Type
                   </button>
+                  <button
+                    className="gp-secondary"
+                    type="button"
+                    onClick={suggestLetter}
+                    disabled={status !== "playing"}
+                  >
+                    💡 Hint
+                  </button>
                 </div>
 
                 {uiMsg && <div style={{ marginTop: 10, opacity: 0.8, fontSize: 13 }}>{uiMsg}</div>}
@@ -440,3 +533,21 @@ function HangmanSvg({ wrong, maxWrong }: { wrong: number; maxWrong: number }) {
     </svg>
   );
 }
+
+function RegionOverlay({ region }: { region: Region }) {
+  return (
+    <svg viewBox="0 0 1 1" preserveAspectRatio="none">
+      {region.kind === "BOX" ? (
+        <rect
+          className="gp-region"
+          x={region.x}
+          y={region.y}
+          width={region.width}
+          height={region.height}
+        />
+      ) : (
+        <polygon className="gp-region" points={region.points.map((p) => `${p.x},${p.y}`).join(" ")} />
+      )}
+    </svg>
+  );
+}
@@ -1,14 +1,15 @@
 import { useMemo, useState } from "react";
 import "../styles/homepage.css";
 
-type Role = "ADMIN" | "USER";
+type Role = "ADMIN" | "MODERATOR" | "USER";
 
 type Props = {
   role?: Role;
+  permissions?: string[];
 
   onLogout?: () => void;
 
-  // ADMIN actions
+  // card management actions (shown by permission)
   onAddNewCard?: () => void;
   onEditCards?: () => void;
   onCardRequests?: () => void;
@@ -22,15 +23,19 @@ type Props = {
     language: "EN" | "LAT";
     difficulty: "EASY" | "HARD";
     maxWrong: number;
+    cardDifficulty: CardDifficulty | null;
+    opponent: CardDifficulty | null;
   }) => void;
 };
 
 type Category = "ORGANS" | "BONES" | "";
 type Language = "EN" | "LAT" | "";
 type Difficulty = "EASY" | "HARD" | "";
+type CardDifficulty = "EASY" | "MEDIUM" | "HARD";
 
 export default function HomePage({
   role,
+  permissions = [],
   onLogout,
   onAddNewCard,
   onEditCards,
@@ -42,6 +47,8 @@ export default function HomePage({
   const [category, setCategory] = useState<Category>("");
   const [language, setLanguage] = useState<Language>("");
   const [difficulty, setDifficulty] = useState<Difficulty>("");
+  const [cardDifficulty, setCardDifficulty] = useState<CardDifficulty | null>(null);
+  const [opponent, setOpponent] = useState<CardDifficulty | null>(null);
 
   const maxWrong = useMemo(() => {
     if (!difficulty) return 0;
@@ -61,6 +68,8 @@ export default function HomePage({
       language: language as "EN" | "LAT",
       difficulty: difficulty as "EASY" | "HARD",
       maxWrong,
+      cardDifficulty,
+      opponent,
     });
   };
 
@@ -76,7 +85,7 @@ export default function HomePage({
           </div>
 
           <div className="hp-actions">
-            {role === "ADMIN" && (
+            {permissions.includes("cards.edit") && (
               <>
                 <button className="hp-ghost" onClick={onAddNewCard} type="button">
                   Add card
@@ -85,14 +94,16 @@ export default function HomePage({
                 <button className="hp-ghost" onClick={onEditCards} type="button">
                   Edit cards
                 </button>
-
-                <button className="hp-ghost" onClick={onCardRequests} type="button">
-                  Card requests
-                </button>
               </>
             )}
 
-            {role === "USER" && (
+            {permissions.includes("cards.review") && (
+              <button className="hp-ghost" onClick={onCardRequests} type="button">
+                Card requests
+              </button>
+            )}
+
+            {role && role !== "ADMIN" && (
               <>
                 <button className="hp-ghost" onClick={onGrowTogether} type="button">
                   Grow together
@@ -199,6 +210,90 @@ export default function HomePage({
           </div>
         </section>
 
+        {/* CARD DIFFICULTY (optional, computed per card) */}
+        <section className="hp-section">
+          <div className="hp-section-header">
+            <span className="hp-section-title">Cards</span>
+            <span className="hp-pill">
+              {cardDifficulty === null
+                ? "Any"
+                : cardDifficulty.charAt(0) + cardDifficulty.slice(1).toLowerCase()}
+            </span>
+          </div>
+
+          <div className="hp-row">
+            <ChoiceCard
+              title="Any"
+              desc="All cards in the category"
+              icon="🃏"
+              selected={cardDifficulty === null}
+              onClick={() => setCardDifficulty(null)}
+            />
+            <ChoiceCard
+              title="Easy cards"
+              desc="Long terms, common letters"
+              icon="🌱"
+              selected={cardDifficulty === "EASY"}
+              onClick={() => setCardDifficulty("EASY")}
+            />
+            <ChoiceCard
+              title="Medium cards"
+              desc="A bit of both"
+              icon="🌿"
+              selected={cardDifficulty === "MEDIUM"}
+              onClick={() => setCardDifficulty("MEDIUM")}
+            />
+            <ChoiceCard
+              title="Hard cards"
+              desc="Short terms, rare letters, often missed"
+              icon="🌵"
+              selected={cardDifficulty === "HARD"}
+              onClick={() => setCardDifficulty("HARD")}
+            />
+          </div>
+        </section>
+
+        {/* OPPONENT (optional bot racing the same card) */}
+        <section className="hp-section">
+          <div className="hp-section-header">
+            <span className="hp-section-title">Opponent</span>
+            <span className="hp-pill">
+              {opponent === null ? "Solo" : opponent.charAt(0) + opponent.slice(1).toLowerCase() + " bot"}
+            </span>
+          </div>
+
+          <div className="hp-row">
+            <ChoiceCard
+              title="Solo"
+              desc="Just you and the cards"
+              icon="🧍"
+              selected={opponent === null}
+              onClick={() => setOpponent(null)}
+            />
+            <ChoiceCard
+              title="Easy bot"
+              desc="Slips up often"
+              icon="🐢"
+              selected={opponent === "EASY"}
+              onClick={() => setOpponent("EASY")}
+            />
+            <ChoiceCard
+              title="Medium bot"
+              desc="Solid, with the odd mistake"
+              icon="🦊"
+              selected={opponent === "MEDIUM"}
+              onClick={() => setOpponent("MEDIUM")}
+            />
+            <ChoiceCard
+              title="Hard bot"
+              desc="Plays the letter odds almost perfectly"
+              icon="🤖"
+              selected={opponent === "HARD"}
+              onClick={() => setOpponent("HARD")}
+            />
+          </div>
+        </section>
+
         <button
           className={`hp-play ${!canPlay ? "disabled" : ""}`}
           onClick={handlePlay}
@@ -1,5 +1,6 @@
 import { useEffect, useMemo, useState } from "react";
-import { invoke } from "@tauri-apps/api/core";
+import { safeInvoke } from "../lib/invoke";
+import { cardImageSrc } from "../lib/cardImage";
 import {
   ResponsiveContainer,
   PieChart,
@@ -17,7 +18,6 @@ import "../styles/statspage.css";
 
 type Props = {
   sessionToken: string;
-  userId: number;
   onBack: () => void;
 };
 
@@ -55,6 +55,16 @@ type DifficultyStats = {
   losses: number;
   winRate: number; // 0..1
 };
+type UserRating = { category: string; rating: number; games: number };
+type SessionItem = {
+  id: number;
+  label: string | null;
+  createdAt: number; // unix seconds
+  lastSeenAt: number;
+  expiresAt: number;
+  current: boolean;
+};
+type BotRecord = { level: string; played: number; wins: number; losses: number; draws: number };
 type UserAnalyticsResponse = {
   daily: DailyPoint[];
   missedByCategory: LabelValue[];
@@ -63,7 +73,7 @@ type UserAnalyticsResponse = {
   wrongCountDist: LabelValue[];
 };
 
-export default function StatsPage({ sessionToken, userId, onBack }: Props) {
+export default function StatsPage({ sessionToken, onBack }: Props) {
   // stats
   const [loading, setLoading] = useState(true);
   const [err, setErr] = useState<string>("");
@@ -74,6 +84,12 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
   const [analytics, setAnalytics] = useState<UserAnalyticsResponse | null>(null);
   const [analyticsErr, setAnalyticsErr] = useState<string>("");
 
+  // skill rating per category (Elo, cards are the opponents)
+  const [ratings, setRatings] = useState<UserRating[]>([]);
+
+  // head-to-head record against each bot level
+  const [botRecord, setBotRecord] = useState<BotRecord[]>([]);
+
   // profile modal
   const [profileOpen, setProfileOpen] = useState(false);
   const [profileLoading, setProfileLoading] = useState(false);
@@ -95,6 +111,10 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
   const [pwErr, setPwErr] = useState<string>("");
   const [pwOk, setPwOk] = useState<string>("");
 
+  // signed-in devices
+  const [sessions, setSessions] = useState<SessionItem[]>([]);
+  const [sessionsErr, setSessionsErr] = useState<string>("");
+
   // -------------------- load stats --------------------
   useEffect(() => {
     let mounted = true;
@@ -103,7 +123,7 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
       setLoading(true);
       setErr("");
       try {
-        const res = await invoke<UserStatsResponse>("get_user_stats", { userId });
+        const res = await safeInvoke<UserStatsResponse>("get_user_stats", { sessionToken });
         if (!mounted) return;
         setData(res);
       } catch (e: any) {
@@ -119,7 +139,7 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
     return () => {
       mounted = false;
     };
-  }, [userId]);
+  }, [sessionToken]);
 
   // -------------------- load analytics --------------------
   useEffect(() => {
@@ -128,7 +148,7 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
     (async () => {
       setAnalyticsErr("");
       try {
-        const res = await invoke<UserAnalyticsResponse>("get_user_analytics", { userId, days: 14 });
+        const res = await safeInvoke<UserAnalyticsResponse>("get_user_analytics", { sessionToken, days: 14 });
         if (!mounted) return;
         setAnalytics(res);
       } catch (e: any) {
@@ -141,7 +161,28 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
     return () => {
       mounted = false;
     };
-  }, [userId]);
+  }, [sessionToken]);
+
+  // -------------------- load ratings --------------------
+  useEffect(() => {
+    let mounted = true;
+
+    safeInvoke<UserRating[]>("get_my_ratings", { sessionToken })
+      .then((res) => {
+        if (mounted) setRatings(res);
+      })
+      .catch((e) => console.error("get_my_ratings failed:", e));
+
+    safeInvoke<BotRecord[]>("get_bot_record", { sessionToken })
+      .then((res) => {
+        if (mounted) setBotRecord(res);
+      })
+      .catch((e) => console.error("get_bot_record failed:", e));
+
+    return () => {
+      mounted = false;
+    };
+  }, [sessionToken]);
 
   const guessed = data?.guessedCount ?? 0;
   const missed = data?.missedCount ?? 0;
@@ -183,7 +224,7 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
 
     setProfileLoading(true);
     try {
-      const p = await invoke<ProfileResponse>("get_profile", { sessionToken });
+      const p = await safeInvoke<ProfileResponse>("get_profile", { sessionToken });
       setProfile(p);
 
       setFirstName(p.firstName ?? "");
@@ -196,6 +237,40 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
     } finally {
       setProfileLoading(false);
     }
+
+    await loadSessions();
+  };
+
+  const loadSessions = async () => {
+    setSessionsErr("");
+    try {
+      setSessions(await safeInvoke<SessionItem[]>("list_sessions", { sessionToken }));
+    } catch (e: any) {
+      console.error("list_sessions failed:", e);
+      setSessionsErr(e?.message ?? "Failed to load sessions.");
+    }
+  };
+
+  const revokeSession = async (sessionId: number) => {
+    setSessionsErr("");
+    try {
+      await safeInvoke<void>("revoke_session", { sessionToken, sessionId });
+      await loadSessions();
+    } catch (e: any) {
+      console.error("revoke_session failed:", e);
+      setSessionsErr(e?.message ?? "Failed to revoke session.");
+    }
+  };
+
+  const revokeOtherSessions = async () => {
+    setSessionsErr("");
+    try {
+      await safeInvoke<number>("revoke_all_other_sessions", { sessionToken });
+      await loadSessions();
+    } catch (e: any) {
+      console.error("revoke_all_other_sessions failed:", e);
+      setSessionsErr(e?.message ?? "Failed to revoke sessions.");
+    }
   };
 
   const closeProfile = () => {
@@ -214,13 +289,13 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
 
     setProfileLoading(true);
     try {
-      await invoke<void>("update_profile", {
+      await safeInvoke<void>("update_profile", {
         req: { sessionToken, firstName, lastName, username, email },
       });
 
       setProfileOk("Profile updated ✅");
 
-      const p = await invoke<ProfileResponse>("get_profile", { sessionToken });
+      const p = await safeInvoke<ProfileResponse>("get_profile", { sessionToken });
       setProfile(p);
       setFirstName(p.firstName ?? "");
       setLastName(p.lastName ?? "");
@@ -249,14 +324,15 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
 
     setProfileLoading(true);
     try {
-      await invoke<void>("change_password", {
+      await safeInvoke<void>("change_password", {
         req: { sessionToken, oldPassword, newPassword },
       });
 
-      setPwOk("Password changed ✅");
+      setPwOk("Password changed ✅ Other devices were logged out.");
       setOldPassword("");
       setNewPassword("");
       setNewPassword2("");
+      await loadSessions();
     } catch (e: any) {
       console.error("change_password failed:", e);
       setPwErr(e?.message ?? "Failed to change password.");
@@ -301,6 +377,30 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
                 <div className="sp-metric-label">Missed</div>
                 <div className="sp-metric-value">{missed}</div>
               </div>
+
+              {ratings.map((r) => (
+                <div className="sp-metric" key={r.category}>
+                  <div className="sp-metric-label">
+                    Rating • {r.category === "BONES" ? "Bones" : "Organs"}
+                  </div>
+                  <div className="sp-metric-value">{Math.round(r.rating)}</div>
+                  <div className="sp-metric-label">{r.games} rated games</div>
+                </div>
+              ))}
+
+              {botRecord.map((b) => (
+                <div className="sp-metric" key={b.level}>
+                  <div className="sp-metric-label">
+                    vs {b.level.charAt(0) + b.level.slice(1).toLowerCase()} bot
+                  </div>
+                  <div className="sp-metric-value">
+                    {b.wins}–{b.losses}
+                  </div>
+                  <div className="sp-metric-label">
+                    {b.draws} draws • {b.played} rounds
+                  </div>
+                </div>
+              ))}
             </div>
 
             {/* ------- Analytics charts ------- */}
@@ -409,7 +509,7 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
                     data.missedCards.map((c) => (
                       <div className="sp-item" key={c.cardId}>
                         <div className="sp-thumb">
-                          {c.imagePath ? <img src={c.imagePath} alt="card" /> : null}
+                          {c.imagePath ? <img src={cardImageSrc(c.imagePath)} alt="card" /> : null}
                         </div>
 
                         <div className="sp-item-main">
@@ -518,6 +618,38 @@ export default function StatsPage({ sessionToken, userId, onBack }: Props) {
                 </button>
               </div>
 
+              <div className="sp-divider" />
+
+              <div className="sp-section-title">Signed-in devices</div>
+              {sessionsErr && <div className="sp-error">{sessionsErr}</div>}
+
+              {sessions.map((s) => (
+                <div className="sp-row" key={s.id} style={{ alignItems: "center" }}>
+                  <div className="sp-field">
+                    <label>{s.label ?? "Unknown device"}</label>
+                    <div className="sp-info">
+                      Signed in {new Date(s.createdAt * 1000).toLocaleString()} • last active{" "}
+                      {new Date(s.lastSeenAt * 1000).toLocaleString()}
+                    </div>
+                  </div>
+                  {s.current ? (
+                    <span className="sp-info">This device</span>
+                  ) : (
+                    <button className="sp-ghost" type="button" onClick={() => revokeSession(s.id)}>
+                      Log out
+                    </button>
+                  )}
+                </div>
+              ))}
+
+              {sessions.some((s) => !s.current) && (
+                <div className="sp-form-actions">
+                  <button className="sp-secondary" type="button" onClick={revokeOtherSessions}>
+                    Log out all other devices
+                  </button>
+                </div>
+              )}
+
               <div className="sp-modal-bottom">
                 <button className="sp-ghost" onClick={closeProfile} type="button">
                   Close
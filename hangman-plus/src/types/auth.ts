@@ -1,4 +1,4 @@
-export type Role = "ADMIN" | "USER";
+export type Role = "ADMIN" | "MODERATOR" | "USER";
 
 export type LoginResponse = {
   session_token: string;
@@ -7,5 +7,6 @@ export type LoginResponse = {
 export type MeResponse = {
   id: number;
   username: string;
-  role: Role; 
+  role: Role;
+  permissions: string[]; // "cards.review", "cards.edit", ...
 };